@@ -79,6 +79,9 @@ extern "C" {
 #define configMINIMAL_STACK_SIZE                ( ( unsigned short ) 50 ) /* In this simulated case, the stack only has to hold one small structure as the real stack is part of the win32 thread. */
 // For development, we use a very large heap size.
 #define configTOTAL_HEAP_SIZE                   1024 * 1024 * 250
+#define configSUPPORT_STATIC_ALLOCATION         1
+/* Let the kernel provide the idle and timer task memory required by static allocation. */
+#define configKERNEL_PROVIDED_STATIC_MEMORY     1
 #define configMAX_TASK_NAME_LEN                 ( 12 )
 #define configUSE_TRACE_FACILITY                1
 #define configUSE_16_BIT_TICKS                  0
@@ -92,7 +95,8 @@ extern "C" {
 #define configUSE_COUNTING_SEMAPHORES           1
 #define configUSE_QUEUE_SETS                    1
 #define configUSE_TASK_NOTIFICATIONS            1
-#define configTASK_NOTIFICATION_ARRAY_ENTRIES   1
+#define configTASK_NOTIFICATION_ARRAY_ENTRIES   3
+#define configNUM_THREAD_LOCAL_STORAGE_POINTERS 4
 
 /* Software timer related configuration options. */
 #define configUSE_TIMERS                        1
@@ -140,6 +144,18 @@ uses the same semantics as the standard C assert() macro. */
 extern void vAssertCalled( const char * const pcFileName, unsigned long ulLine );
 #define configASSERT( x ) if( ( x ) == 0 ) vAssertCalled( __FILE__, __LINE__ )
 
+/* Forward queue traffic to the `unsafe-hooks-queue-event` hook of `veecle-freertos-integration`. The hook is only
+defined with that feature enabled, define configUSE_QUEUE_EVENT_HOOK to 0 to link without it. */
+#ifndef configUSE_QUEUE_EVENT_HOOK
+#define configUSE_QUEUE_EVENT_HOOK 1
+#endif
+
+#if ( configUSE_QUEUE_EVENT_HOOK == 1 )
+extern void vQueueEventCalled( void * xQueue, unsigned long ulEvent );
+#define traceQUEUE_SEND( pxQueue ) vQueueEventCalled( ( pxQueue ), 0 )
+#define traceQUEUE_RECEIVE( pxQueue ) vQueueEventCalled( ( pxQueue ), 1 )
+#endif
+
 /* Include the FreeRTOS+Trace FreeRTOS trace macro definitions. */
 #define TRACE_ENTER_CRITICAL_SECTION() portENTER_CRITICAL()
 #define TRACE_EXIT_CRITICAL_SECTION() portEXIT_CRITICAL()
@@ -87,6 +87,12 @@ const BOOLEAN_CONFIGS: &[&str] = &[
     "INCLUDE_xTimerPendFunctionCall",
 ];
 
+/// All configuration options that enable a feature when non-zero, like a number of slots.
+const NON_ZERO_CONFIGS: &[&str] = &[
+    "configNUM_THREAD_LOCAL_STORAGE_POINTERS",
+    "configQUEUE_REGISTRY_SIZE",
+];
+
 /// All configuration options using a set of predetermined values.
 /// Configuration options that are not limited to a set of values like "configCPU_CLOCK_HZ" will not be included.
 const VALUE_CONFIGS: &[(&str, &[u64])] = &[
@@ -100,6 +106,7 @@ fn main() {
     let bindings_path = PathBuf::from(env::var(FREERTOS_BINDINGS_LOCATION_ENV_KEY).unwrap());
     let bindings = fs::read_to_string(bindings_path).unwrap();
     emit_on_one(BOOLEAN_CONFIGS, &bindings);
+    emit_on_non_zero(NON_ZERO_CONFIGS, &bindings);
     emit_value(VALUE_CONFIGS, &bindings);
 }
 
@@ -147,6 +154,25 @@ fn emit_on_one(list: &[&str], bindings: &str) {
     }
 }
 
+/// Emits `cfg`s for parsed configuration entries from the `FreeRTOSConfig.h` file.
+///
+/// Sets the `cfg` for any non-zero value. For exact values, see [`emit_value`].
+///
+/// This enables conditional inclusion of code that depends on the configuration of the FreeRTOS library.
+///
+/// # Panics
+///
+/// Panics on expected configuration entries not being present in the bindings.
+fn emit_on_non_zero(list: &[&str], bindings: &str) {
+    for item in list {
+        if parse_config(item, bindings) != 0 {
+            println!("cargo::rustc-cfg={item}");
+        }
+
+        println!("cargo::rustc-check-cfg=cfg({item}, values(none()))");
+    }
+}
+
 /// Emits `cfg`s for parsed configuration entries from the `FreeRTOSConfig.h` file with their respective value.
 ///
 /// For set or not-set cfg support, see [`emit_value`].
@@ -25,25 +25,23 @@ impl FreeRtosAllocator {
     pub const unsafe fn new() -> Self {
         Self { _private: () }
     }
-}
-
-// Rust standard library implements the same strategy for Windows:
-// https://github.com/rust-lang/rust/blob/master/library/std/src/sys/alloc/windows.rs#L227
-// https://github.com/rust-lang/rust/blob/master/library/std/src/sys/alloc/windows.rs#L157
 
-/// This relies on the `pvPortMalloc` macro to return memory that is aligned to `portByteAlignment`.
-// SAFETY:
-// The given `Layout` is checked to make sure the proper memory address and amount are used for the (de)allocate
-// operation. If there is any error during this process, or there is no way to allocate the requested memory,
-// `ptr::null_mut()` is returned by default.
-unsafe impl GlobalAlloc for FreeRtosAllocator {
-    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+    /// Allocates memory for `layout` without reporting failures, see [`GlobalAlloc::alloc`].
+    ///
+    /// # Safety
+    ///
+    /// Same requirements as [`GlobalAlloc::alloc`].
+    unsafe fn alloc_inner(&self, layout: Layout) -> *mut u8 {
         // If the requested alignment is smaller than the port alignment, the alignment for the request is fulfilled.
         // This is because every smaller power of two is correctly aligned on every larger power of two.
         // E.g.: requested 8, received 32 => still correctly aligned
         if layout.align() <= usize::from(portBYTE_ALIGNMENT) {
             pvPortMalloc(layout.size()).cast()
         } else {
+            // Rust standard library implements the same strategy for Windows:
+            // https://github.com/rust-lang/rust/blob/master/library/std/src/sys/alloc/windows.rs#L227
+            // https://github.com/rust-lang/rust/blob/master/library/std/src/sys/alloc/windows.rs#L157
+            //
             // There are architectures where `portBYTE_ALIGNMENT` is smaller than the size of a pointer.
             // Example: https://github.com/FreeRTOS/FreeRTOS-Kernel/blob/main/portable/IAR/AVR_Mega0/portmacro.h#L48-L89
             // Therefore we cannot assume there is enough space for `OriginalPointer` if we only account for alignment
@@ -147,6 +145,25 @@ unsafe impl GlobalAlloc for FreeRtosAllocator {
             layout_memory_region
         }
     }
+}
+
+/// This relies on the `pvPortMalloc` macro to return memory that is aligned to `portByteAlignment`.
+// SAFETY:
+// The given `Layout` is checked to make sure the proper memory address and amount are used for the (de)allocate
+// operation. If there is any error during this process, or there is no way to allocate the requested memory,
+// `ptr::null_mut()` is returned by default.
+unsafe impl GlobalAlloc for FreeRtosAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        // SAFETY: We forward the safety requirements to our caller.
+        let ptr = unsafe { self.alloc_inner(layout) };
+
+        #[cfg(feature = "hooks-alloc-failure")]
+        if ptr.is_null() {
+            crate::hooks::alloc_failure(layout);
+        }
+
+        ptr
+    }
 
     unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
         if layout.align() <= usize::from(portBYTE_ALIGNMENT) {
@@ -188,4 +205,32 @@ unsafe impl GlobalAlloc for FreeRtosAllocator {
             unsafe { vPortFree(original_pointer.cast()) }
         }
     }
+
+    /// Shrinks reuse the existing block, growing allocates a new block, copies the contents and frees the old block.
+    ///
+    /// The FreeRTOS heap implementations don't expose the size of a block, so growing in place is not possible.
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        // `dealloc` doesn't depend on the size of the layout, so the block can be kept as-is when shrinking. This holds
+        // for both the `portBYTE_ALIGNMENT` and the over-aligned path, as the alignment doesn't change.
+        if new_size <= layout.size() {
+            return ptr;
+        }
+
+        // SAFETY:
+        // The caller guarantees that `new_size`, rounded up to `layout.align()`, does not overflow `isize`.
+        let new_layout = unsafe { Layout::from_size_align_unchecked(new_size, layout.align()) };
+
+        // SAFETY:
+        // The caller guarantees that `new_size` is greater than zero.
+        let new_ptr = unsafe { self.alloc(new_layout) };
+        if !new_ptr.is_null() {
+            // SAFETY:
+            // Both blocks are valid for at least `layout.size()` bytes and a fresh allocation can't overlap `ptr`.
+            unsafe { ptr::copy_nonoverlapping(ptr, new_ptr, layout.size()) };
+            // SAFETY:
+            // The caller guarantees `ptr` was allocated by this allocator with `layout`.
+            unsafe { self.dealloc(ptr, layout) };
+        }
+        new_ptr
+    }
 }
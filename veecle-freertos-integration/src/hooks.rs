@@ -1,6 +1,59 @@
+#[cfg(feature = "hooks-alloc-failure")]
+pub(crate) use on_alloc_failure::alloc_failure;
+#[cfg(feature = "hooks-alloc-failure")]
+pub use on_alloc_failure::{OnAllocFailureFn, set_on_alloc_failure};
+#[cfg(all(feature = "unsafe-hooks-assert", feature = "std"))]
+pub use on_assert::{AssertInfo, set_structured_assert_panic};
 #[cfg(feature = "unsafe-hooks-assert")]
 pub use on_assert::{OnAssertFn, set_on_assert};
 
+#[cfg(feature = "unsafe-hooks-queue-event")]
+pub use on_queue_event::{OnQueueEventFn, QueueEvent, set_on_queue_event};
+#[cfg(feature = "std")]
+pub(crate) use on_task_panic::run_catching_panics;
+#[cfg(feature = "std")]
+pub use on_task_panic::{OnTaskPanicFn, set_on_task_panic};
+#[cfg(feature = "posix-test")]
+pub(crate) use on_tick_rate_mismatch::tick_rate_mismatch;
+#[cfg(feature = "posix-test")]
+pub use on_tick_rate_mismatch::{OnTickRateMismatchFn, set_on_tick_rate_mismatch};
+
+#[cfg(feature = "hooks-alloc-failure")]
+mod on_alloc_failure {
+    use core::alloc::Layout;
+    use core::sync::atomic::AtomicPtr;
+    use core::sync::atomic::Ordering::{Acquire, Release};
+    use core::{mem, ptr};
+
+    /// Alias for the allocation failure hook function signature.
+    // Keeps all uses of the `on_alloc_failure` function in sync.
+    pub type OnAllocFailureFn = fn(layout: Layout);
+
+    /// Allocation failure hook.
+    static ON_ALLOC_FAILURE: AtomicPtr<()> = AtomicPtr::new(ptr::null_mut());
+
+    /// Sets the hook invoked with the requested [`Layout`] when [`FreeRtosAllocator`](crate::FreeRtosAllocator) fails
+    /// to allocate, before the null pointer is returned to the Rust alloc error handler.
+    ///
+    /// The hook runs inside the global allocator: it must not unwind (unwinding out of an allocator is undefined
+    /// behavior) and must not allocate. Record or print the size and alignment, then return or abort.
+    pub fn set_on_alloc_failure(on_alloc_failure_fn: OnAllocFailureFn) {
+        ON_ALLOC_FAILURE.store(on_alloc_failure_fn as *mut (), Release);
+    }
+
+    /// Calls the allocation failure hook, if set.
+    pub(crate) fn alloc_failure(layout: Layout) {
+        let on_alloc_failure_fn = ON_ALLOC_FAILURE.load(Acquire);
+        if !on_alloc_failure_fn.is_null() {
+            // SAFETY: If the pointer is non-null, it must be a pointer to a function (set in `set_on_alloc_failure`)
+            // and we just checked that the pointer is not null.
+            let on_alloc_failure_fn: OnAllocFailureFn =
+                unsafe { mem::transmute(on_alloc_failure_fn) };
+            on_alloc_failure_fn(layout);
+        }
+    }
+}
+
 #[cfg(feature = "unsafe-hooks-assert")]
 mod on_assert {
     use core::ffi::c_ulong;
@@ -24,6 +77,30 @@ mod on_assert {
         ON_ASSERT.store(on_assert_fn as *mut (), Release);
     }
 
+    /// Location of a failed `configASSERT`, used as panic payload if enabled via [`set_structured_assert_panic`].
+    #[cfg(feature = "std")]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct AssertInfo {
+        /// Name of the file containing the assert.
+        pub file: &'static str,
+        /// Line of the assert.
+        pub line: u32,
+    }
+
+    /// Whether `vAssertCalled` panics with an [`AssertInfo`] payload.
+    #[cfg(feature = "std")]
+    static STRUCTURED_PANIC: core::sync::atomic::AtomicBool =
+        core::sync::atomic::AtomicBool::new(false);
+
+    /// Sets whether `vAssertCalled` panics with an [`AssertInfo`] payload (via [`std::panic::panic_any`]) instead of
+    /// a formatted message.
+    ///
+    /// This allows a panic hook to downcast the payload to extract the assert location.
+    #[cfg(feature = "std")]
+    pub fn set_structured_assert_panic(enabled: bool) {
+        STRUCTURED_PANIC.store(enabled, Release);
+    }
+
     // SAFETY:
     // We require the user of this crate to promise to use the correct prototype (declared in
     // `Cargo.toml`) to call this unmangled function from any external code when activating the
@@ -53,6 +130,159 @@ mod on_assert {
             on_assert_fn(file_name, line)
         }
 
+        #[cfg(feature = "std")]
+        if STRUCTURED_PANIC.load(Acquire) {
+            // The file name is only valid during this call, the copy is leaked as we will not return anyway.
+            let file = alloc::boxed::Box::leak(alloc::boxed::Box::<str>::from(file_name));
+            std::panic::panic_any(AssertInfo {
+                file,
+                line: u32::try_from(line).unwrap_or(u32::MAX),
+            });
+        }
+
         panic!("FreeRTOS ASSERT: {}:{}", file_name, line);
     }
 }
+
+#[cfg(feature = "unsafe-hooks-queue-event")]
+mod on_queue_event {
+    use core::ffi::{c_ulong, c_void};
+    use core::sync::atomic::AtomicPtr;
+    use core::sync::atomic::Ordering::{Acquire, Release};
+    use core::{mem, ptr};
+
+    use veecle_freertos_sys::bindings::QueueHandle_t;
+
+    /// Kind of queue traffic reported to the `vQueueEventCalled` hook.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+    pub enum QueueEvent {
+        /// An item was sent to the queue.
+        Send,
+        /// An item was received from the queue.
+        Receive,
+    }
+
+    /// Alias for the queue event hook function signature.
+    // Keeps all uses of the `on_queue_event` function in sync.
+    pub type OnQueueEventFn = fn(queue: QueueHandle_t, event: QueueEvent);
+
+    /// `vQueueEventCalled` hook.
+    static ON_QUEUE_EVENT: AtomicPtr<()> = AtomicPtr::new(ptr::null_mut());
+
+    /// Sets the `vQueueEventCalled` hook, observing sends and receives on all queues.
+    ///
+    /// This includes queues used internally by FreeRTOS and this crate, e.g. the timer command queue and semaphores.
+    ///
+    /// The hook runs inside the kernel's queue operations, with interrupts disabled by a critical section. It must be
+    /// short, must not block and must not call FreeRTOS API functions.
+    ///
+    /// See [trace hook macros][trace_hooks] for more details.
+    ///
+    /// [trace_hooks]: https://www.freertos.org/Documentation/02-Kernel/02-Kernel-features/09-RTOS-trace-feature
+    pub fn set_on_queue_event(on_queue_event_fn: OnQueueEventFn) {
+        ON_QUEUE_EVENT.store(on_queue_event_fn as *mut (), Release);
+    }
+
+    // SAFETY:
+    // We require the user of this crate to promise to use the correct prototype (declared in
+    // `Cargo.toml`) to call this unmangled function from any external code when activating the
+    // feature.
+    #[unsafe(no_mangle)]
+    extern "C" fn vQueueEventCalled(queue: *mut c_void, event: c_ulong) {
+        let on_queue_event_fn = ON_QUEUE_EVENT.load(Acquire);
+        if on_queue_event_fn.is_null() {
+            return;
+        }
+
+        let event = match event {
+            0 => QueueEvent::Send,
+            1 => QueueEvent::Receive,
+            _ => return,
+        };
+
+        // SAFETY: If the pointer is non-null, it must be a pointer to a function (set in `Self::set_on_queue_event`)
+        // and we just checked that the pointer is not null.
+        let on_queue_event_fn: OnQueueEventFn = unsafe { mem::transmute(on_queue_event_fn) };
+        on_queue_event_fn(queue.cast(), event)
+    }
+}
+
+#[cfg(feature = "std")]
+mod on_task_panic {
+    use alloc::boxed::Box;
+    use core::any::Any;
+    use core::sync::atomic::AtomicPtr;
+    use core::sync::atomic::Ordering::{Acquire, Release};
+    use core::{mem, ptr};
+    use std::panic::{AssertUnwindSafe, catch_unwind};
+
+    use crate::{CurrentTask, Task};
+
+    /// Alias for the task panic hook function signature.
+    // Keeps all uses of the `on_task_panic` function in sync.
+    pub type OnTaskPanicFn = fn(task: Task, payload: Box<dyn Any + Send>);
+
+    /// Task panic hook.
+    static ON_TASK_PANIC: AtomicPtr<()> = AtomicPtr::new(ptr::null_mut());
+
+    /// Sets the hook invoked with the panic payload when a task started with
+    /// [`TaskBuilder::catch_panics`](crate::TaskBuilder::catch_panics) panics.
+    ///
+    /// The hook runs on the panicked task, which is suspended forever once the hook returns.
+    pub fn set_on_task_panic(on_task_panic_fn: OnTaskPanicFn) {
+        ON_TASK_PANIC.store(on_task_panic_fn as *mut (), Release);
+    }
+
+    /// Runs `func`, routing a panic to the task panic hook and parking the task afterwards.
+    ///
+    /// Requires `panic = "unwind"`, with `panic = "abort"` the program aborts as usual.
+    pub(crate) fn run_catching_panics(task: Task, func: impl FnOnce(Task)) {
+        let Err(payload) = catch_unwind(AssertUnwindSafe(|| func(task.clone()))) else {
+            return;
+        };
+
+        let on_task_panic_fn = ON_TASK_PANIC.load(Acquire);
+        if !on_task_panic_fn.is_null() {
+            // SAFETY: If the pointer is non-null, it must be a pointer to a function (set in `set_on_task_panic`) and
+            // we just checked that the pointer is not null.
+            let on_task_panic_fn: OnTaskPanicFn = unsafe { mem::transmute(on_task_panic_fn) };
+            on_task_panic_fn(task, payload);
+        }
+
+        loop {
+            CurrentTask::suspend();
+        }
+    }
+}
+
+#[cfg(feature = "posix-test")]
+mod on_tick_rate_mismatch {
+    use core::sync::atomic::AtomicPtr;
+    use core::sync::atomic::Ordering::{Acquire, Release};
+    use core::{mem, ptr};
+
+    /// Alias for the tick rate mismatch hook function signature.
+    // Keeps all uses of the `on_tick_rate_mismatch` function in sync.
+    pub type OnTickRateMismatchFn = fn(measured_hz: u32, configured_hz: u32);
+
+    /// Tick rate mismatch hook.
+    static ON_TICK_RATE_MISMATCH: AtomicPtr<()> = AtomicPtr::new(ptr::null_mut());
+
+    /// Sets the hook invoked when [`effective_tick_rate`](crate::scheduler::effective_tick_rate) measures a tick rate
+    /// outside of the tolerance.
+    pub fn set_on_tick_rate_mismatch(on_tick_rate_mismatch_fn: OnTickRateMismatchFn) {
+        ON_TICK_RATE_MISMATCH.store(on_tick_rate_mismatch_fn as *mut (), Release);
+    }
+
+    /// Calls the tick rate mismatch hook, if set.
+    pub(crate) fn tick_rate_mismatch(measured_hz: u32, configured_hz: u32) {
+        let on_tick_rate_mismatch_fn = ON_TICK_RATE_MISMATCH.load(Acquire);
+        if !on_tick_rate_mismatch_fn.is_null() {
+            // SAFETY: If the pointer is non-null, it must be a pointer to a function (set in
+            // `set_on_tick_rate_mismatch`) and we just checked that the pointer is not null.
+            let on_tick_rate_mismatch_fn: OnTickRateMismatchFn =
+                unsafe { mem::transmute(on_tick_rate_mismatch_fn) };
+            on_tick_rate_mismatch_fn(measured_hz, configured_hz);
+        }
+    }
+}
@@ -1,12 +1,14 @@
-use veecle_freertos_sys::bindings::{BaseType_t, taskYIELD};
+use veecle_freertos_sys::bindings::{BaseType_t, pdFALSE, pdTRUE, portYIELD_FROM_ISR};
 
 /// Keep track of whether we need to yield the execution to a different
 /// task at the end of the interrupt.
 ///
-/// Should be dropped as the last thing inside a interrupt.
+/// Should be dropped as the last thing inside a interrupt. To yield at a different point, use
+/// [`into_woken`](InterruptContext::into_woken) or [`set_yield_on_drop`](InterruptContext::set_yield_on_drop).
 #[derive(Debug)]
 pub struct InterruptContext {
     x_higher_priority_task_woken: BaseType_t,
+    yield_on_drop: bool,
 }
 
 impl Default for InterruptContext {
@@ -17,24 +19,91 @@ impl Default for InterruptContext {
 
 impl InterruptContext {
     /// Instantiate a new context.
-    pub fn new() -> InterruptContext {
+    ///
+    /// This is a `const fn`, so contexts can be created in `const` and `static` initializers.
+    pub const fn new() -> InterruptContext {
         InterruptContext {
             x_higher_priority_task_woken: 0,
+            yield_on_drop: true,
         }
     }
 
+    /// Runs `f` with a new context and yields once at the end if any operation in `f` woke a higher priority task.
+    ///
+    /// Useful for interrupts performing several operations, which all feed the same context.
+    pub fn scope<R>(f: impl FnOnce(&mut InterruptContext) -> R) -> R {
+        let mut context = InterruptContext::new();
+        let result = f(&mut context);
+        // Dropping the context yields if needed.
+        drop(context);
+        result
+    }
+
+    /// Consumes `other` without yielding and merges whether it woke a higher priority task into this context.
+    ///
+    /// This lets nested code use its own context while preserving a single yield at the end of the interrupt.
+    pub fn merge(&mut self, other: InterruptContext) {
+        let woken = other.into_woken();
+        if woken != pdFALSE() {
+            self.x_higher_priority_task_woken = woken;
+        }
+    }
+
+    /// Sets whether dropping the context yields if a higher priority task was woken. Enabled by default.
+    ///
+    /// When disabled, the caller is responsible for yielding based on
+    /// [`higher_priority_task_woken`](Self::higher_priority_task_woken).
+    pub fn set_yield_on_drop(&mut self, yield_on_drop: bool) {
+        self.yield_on_drop = yield_on_drop;
+    }
+
+    /// Consumes the context without yielding and returns whether a higher priority task was woken.
+    ///
+    /// The caller is responsible for yielding if the returned value is `pdTRUE`, e.g. by passing it to
+    /// [`portYIELD_FROM_ISR`].
+    pub fn into_woken(mut self) -> BaseType_t {
+        self.yield_on_drop = false;
+        self.x_higher_priority_task_woken
+    }
+
+    /// Runs a FreeRTOS `*FromISR` operation, passing it a fresh woken flag, and returns whether it returned `pdTRUE`.
+    ///
+    /// The woken flag is only merged into this context if the operation succeeded, so a failed operation never
+    /// requests a yield.
+    pub(crate) fn run_operation(
+        &mut self,
+        operation: impl FnOnce(*mut BaseType_t) -> BaseType_t,
+    ) -> bool {
+        let mut woken = pdFALSE();
+        let success = operation(&raw mut woken) == pdTRUE();
+
+        if success && woken != pdFALSE() {
+            self.x_higher_priority_task_woken = woken;
+        }
+
+        success
+    }
+
     pub fn get_task_field_mut(&mut self) -> *mut BaseType_t {
         &raw mut self.x_higher_priority_task_woken
     }
     pub fn higher_priority_task_woken(&self) -> BaseType_t {
         self.x_higher_priority_task_woken
     }
+
+    /// Returns whether an operation woke a higher priority task, so the interrupt should yield before returning.
+    ///
+    /// Unlike [`higher_priority_task_woken`](Self::higher_priority_task_woken) this does not expose the raw
+    /// `BaseType_t` flag.
+    pub fn should_yield(&self) -> bool {
+        self.x_higher_priority_task_woken != pdFALSE()
+    }
 }
 
 impl Drop for InterruptContext {
     fn drop(&mut self) {
-        if self.x_higher_priority_task_woken == 1 {
-            taskYIELD()
+        if self.yield_on_drop && self.should_yield() {
+            portYIELD_FROM_ISR(self.x_higher_priority_task_woken)
         }
     }
 }
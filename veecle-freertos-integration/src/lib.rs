@@ -14,15 +14,25 @@
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
 extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
 
 mod allocator;
 pub mod hooks;
 mod isr;
+pub mod logger;
 mod queue;
+#[cfg(configUSE_QUEUE_SETS)]
+mod queue_set;
+mod rate_limiter;
+mod schedule;
 pub mod scheduler;
+#[cfg(configUSE_COUNTING_SEMAPHORES)]
+mod semaphore;
 pub mod task;
 mod timers;
 mod units;
+mod waker_set;
 
 pub use veecle_freertos_sys::bindings::{
     BaseType_t, QueueHandle_t, TaskHandle_t, TickType_t, TimerHandle_t, UBaseType_t, eNotifyAction,
@@ -33,7 +43,13 @@ pub use veecle_freertos_sys::error::FreeRtosError;
 pub use crate::allocator::*;
 pub use crate::isr::*;
 pub use crate::queue::*;
+#[cfg(configUSE_QUEUE_SETS)]
+pub use crate::queue_set::*;
+pub use crate::rate_limiter::RateLimiter;
+pub use crate::schedule::{Schedule, ScheduleBuilder};
+#[cfg(configUSE_COUNTING_SEMAPHORES)]
+pub use crate::semaphore::AsyncSemaphore;
 #[doc(inline)]
 pub use crate::task::*;
 pub use crate::timers::*;
-pub use crate::units::Duration;
+pub use crate::units::{Duration, Instant, InstantFromIsr, tick_rate_hz};
@@ -0,0 +1,221 @@
+//! Asynchronous logging through a dedicated logger task.
+//!
+//! Producers format a [`LogRecord`] into a fixed-size buffer and enqueue it without blocking, from tasks via
+//! [`Logger::log`] (or [`freertos_log!`](crate::freertos_log)) and from interrupts via [`Logger::log_from_isr`]. The
+//! logger task started by [`LoggerTask::start`] passes the records to a user sink in the order they were enqueued, so
+//! slow I/O never blocks the producers.
+
+use core::ffi::CStr;
+use core::fmt::{self, Debug, Display, Write};
+
+use veecle_freertos_sys::bindings::{StackType_t, TickType_t, UBaseType_t};
+
+use crate::units::Duration;
+use crate::{FreeRtosError, InterruptContext, Queue, Task, TaskPriority, scheduler};
+
+/// Maximum length of a log message in bytes, longer messages are truncated.
+pub const LOG_MESSAGE_CAPACITY: usize = 96;
+
+/// A log message together with where and when it was logged.
+#[derive(Clone)]
+pub struct LogRecord {
+    tick: TickType_t,
+    task: Option<Task>,
+    length: usize,
+    message: [u8; LOG_MESSAGE_CAPACITY],
+}
+
+impl LogRecord {
+    /// Formats `args` into a new record, truncating the message to [`LOG_MESSAGE_CAPACITY`] bytes.
+    fn new(tick: TickType_t, task: Option<Task>, args: fmt::Arguments<'_>) -> Self {
+        let mut record = Self {
+            tick,
+            task,
+            length: 0,
+            message: [0; LOG_MESSAGE_CAPACITY],
+        };
+        // Truncation is not an error, so formatting can't fail.
+        let _ = record.write_fmt(args);
+        record
+    }
+
+    /// Returns the tick count at which the record was logged.
+    pub fn tick(&self) -> TickType_t {
+        self.tick
+    }
+
+    /// Returns the task that logged the record, `None` if it was logged from an interrupt.
+    pub fn task(&self) -> Option<&Task> {
+        self.task.as_ref()
+    }
+
+    /// Returns the, possibly truncated, message.
+    pub fn message(&self) -> &str {
+        // `write_str` only copies whole characters.
+        core::str::from_utf8(&self.message[..self.length]).expect("message should be valid UTF-8")
+    }
+}
+
+impl Write for LogRecord {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let mut length = s.len().min(LOG_MESSAGE_CAPACITY - self.length);
+        while !s.is_char_boundary(length) {
+            length -= 1;
+        }
+        self.message[self.length..][..length].copy_from_slice(&s.as_bytes()[..length]);
+        self.length += length;
+        Ok(())
+    }
+}
+
+impl Debug for LogRecord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LogRecord")
+            .field("tick", &self.tick)
+            .field("task", &self.task)
+            .field("message", &self.message())
+            .finish()
+    }
+}
+
+/// Formats as `<tick> <task name>: <message>`, with `ISR` as task name for records logged from an interrupt.
+///
+/// Looking up the task name allocates, so this should be used from the sink rather than the producer.
+impl Display for LogRecord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ", self.tick)?;
+        match &self.task {
+            Some(task) => match task.get_name() {
+                Ok(name) => f.write_str(&name)?,
+                Err(()) => f.write_str("<unknown>")?,
+            },
+            None => f.write_str("ISR")?,
+        }
+        write!(f, ": {}", self.message())
+    }
+}
+
+/// Handle to enqueue records to a logger task, see [`LoggerTask::start`].
+#[derive(Debug, Clone)]
+pub struct Logger {
+    queue: Queue<LogRecord>,
+}
+
+impl Logger {
+    /// Enqueues a record formatted from `args` without blocking.
+    ///
+    /// Returns `false` if the record was dropped because the queue is full.
+    pub fn log(&self, args: fmt::Arguments<'_>) -> bool {
+        let record = LogRecord::new(scheduler::get_tick_count(), Task::current().ok(), args);
+        self.queue.send(record, Duration::zero()).is_ok()
+    }
+
+    /// Enqueues a record formatted from `args` from an interrupt.
+    ///
+    /// Returns `false` if the record was dropped because the queue is full.
+    pub fn log_from_isr(&self, context: &mut InterruptContext, args: fmt::Arguments<'_>) -> bool {
+        let record = LogRecord::new(scheduler::get_tick_count_from_isr(), None, args);
+        self.queue.send_from_isr(context, record).is_ok()
+    }
+}
+
+/// Builder for the task emitting [`LogRecord`]s to a sink.
+pub struct LoggerTask<S> {
+    name: &'static CStr,
+    capacity: UBaseType_t,
+    priority: TaskPriority,
+    stack_size: Option<StackType_t>,
+    sink: S,
+}
+
+impl<S> LoggerTask<S>
+where
+    S: FnMut(&LogRecord) + Send + 'static,
+{
+    /// Creates a new logger task builder with a queue holding up to `capacity` records, emitting them to `sink`.
+    pub fn new(capacity: UBaseType_t, sink: S) -> Self {
+        Self {
+            name: c"logger",
+            capacity,
+            priority: TaskPriority(1),
+            stack_size: None,
+            sink,
+        }
+    }
+
+    /// Sets the name of the FreeRTOS task, defaults to `logger`.
+    pub fn name(mut self, name: &'static CStr) -> Self {
+        self.name = name;
+        self
+    }
+
+    /// Sets the priority of the FreeRTOS task.
+    pub fn priority(mut self, priority: TaskPriority) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// Sets the stack size of the FreeRTOS task, defaults to the [`Task::new`] default.
+    pub fn stack_size(mut self, stack_size: StackType_t) -> Self {
+        self.stack_size = Some(stack_size);
+        self
+    }
+
+    /// Creates the queue, starts the logger task and returns a [`Logger`] to enqueue records.
+    pub fn start(self) -> Result<Logger, FreeRtosError> {
+        let Self {
+            name,
+            capacity,
+            priority,
+            stack_size,
+            mut sink,
+        } = self;
+
+        let queue = Queue::new(capacity)?;
+        let receiver = queue.clone();
+
+        let mut builder = Task::new();
+        builder.name(name).priority(priority);
+        if let Some(stack_size) = stack_size {
+            builder.stack_size(stack_size);
+        }
+        builder.start(move |_| {
+            loop {
+                if let Ok(record) = receiver.receive(Duration::infinite()) {
+                    sink(&record);
+                }
+            }
+        })?;
+
+        Ok(Logger { queue })
+    }
+}
+
+impl<S> Debug for LoggerTask<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LoggerTask")
+            .field("name", &self.name)
+            .field("capacity", &self.capacity)
+            .field("priority", &self.priority)
+            .field("stack_size", &self.stack_size)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Enqueues a formatted record to a [`Logger`] without blocking, evaluating to `false` if it was dropped.
+///
+/// From an interrupt, pass the [`InterruptContext`] with `isr: context`.
+///
+/// ```ignore
+/// freertos_log!(logger, "sensor value: {}", value);
+/// freertos_log!(logger, isr: &mut context, "overrun on channel {}", channel);
+/// ```
+#[macro_export]
+macro_rules! freertos_log {
+    ($logger:expr, isr: $context:expr, $($arg:tt)+) => {
+        $crate::logger::Logger::log_from_isr(&$logger, $context, ::core::format_args!($($arg)+))
+    };
+    ($logger:expr, $($arg:tt)+) => {
+        $crate::logger::Logger::log(&$logger, ::core::format_args!($($arg)+))
+    };
+}
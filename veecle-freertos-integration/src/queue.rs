@@ -1,19 +1,27 @@
+use alloc::boxed::Box;
 use alloc::sync::Arc;
 use core::ffi::CStr;
 use core::future::poll_fn;
 use core::marker::PhantomData;
 use core::mem::{ManuallyDrop, MaybeUninit};
-use core::task::Poll;
+use core::ptr;
+use core::sync::atomic::Ordering::{AcqRel, Acquire, Release};
+use core::sync::atomic::{AtomicBool, AtomicUsize};
+use core::task::{Context, Poll};
 
 use atomic_waker::AtomicWaker;
 use veecle_freertos_sys::bindings::{
-    QueueHandle_t, StackType_t, UBaseType_t, pdTRUE, shim_xQueueCreate, shim_xQueueReceive,
-    shim_xQueueSendToBack, uxQueueMessagesWaiting, uxQueueSpacesAvailable, vQueueDelete,
+    QueueHandle_t, StackType_t, TickType_t, UBaseType_t, pdTRUE, shim_xQueueCreate,
+    shim_xQueueReceive, shim_xQueueSendToBack, uxQueueGetQueueLength, uxQueueMessagesWaiting,
+    uxQueueSpacesAvailable, vQueueDelete,
 };
+#[cfg(configQUEUE_REGISTRY_SIZE)]
+use veecle_freertos_sys::bindings::{pcQueueGetName, vQueueAddToRegistry};
 
 use crate::isr::InterruptContext;
-use crate::units::Duration;
-use crate::{FreeRtosError, Task, TaskPriority};
+use crate::units::{Duration, Instant};
+use crate::waker_set::WakerSet;
+use crate::{CurrentTask, FreeRtosError, Task, TaskPriority, scheduler};
 
 /// A blocking queue with a finite size. For an asynchronous queue, see [`AsyncQueueSender`] and
 /// [`AsyncQueueReceiver`].
@@ -34,13 +42,19 @@ use crate::{FreeRtosError, Task, TaskPriority};
 #[derive(Debug)]
 pub struct Queue<T> {
     handle: QueueHandle_t,
+    /// Maximum number of items the queue can hold.
+    capacity: UBaseType_t,
+    /// Callback passed to [`Queue::new_with_on_full`].
+    on_full: Option<fn()>,
     item_type: PhantomData<T>,
 }
 
-// SAFETY: The queue struct only contains a pointer to the FreeRTOS resource so it is always Send.
+// SAFETY: The queue struct contains a pointer to the FreeRTOS resource, which FreeRTOS synchronizes internally, the
+// capacity and the `on_full` callback, which are never modified, so it is always Send.
 unsafe impl<T> Send for Queue<T> {}
 
-// SAFETY: The queue struct only contains a pointer to the FreeRTOS resource so it is always Sync.
+// SAFETY: The queue struct contains a pointer to the FreeRTOS resource, which FreeRTOS synchronizes internally, the
+// capacity and the `on_full` callback, which are never modified, so it is always Sync.
 unsafe impl<T> Sync for Queue<T> {}
 
 impl<T> Unpin for Queue<T> {}
@@ -65,6 +79,73 @@ where
 
         Ok(Queue {
             handle,
+            capacity: max_size,
+            on_full: None,
+            item_type: PhantomData,
+        })
+    }
+
+    /// Creates a new `Queue` like [`new`](Self::new), invoking `on_full` whenever a send fails because the queue is
+    /// full.
+    ///
+    /// The callback is shared by all clones of the returned queue, including the wrappers built from them. Handles
+    /// created with [`from_raw_handle`](Self::from_raw_handle) don't invoke it. The callback may be invoked from
+    /// [`send_from_isr`](Self::send_from_isr), so it must be safe to call from ISR contexts.
+    pub fn new_with_on_full(
+        max_size: UBaseType_t,
+        on_full: fn(),
+    ) -> Result<Queue<T>, FreeRtosError> {
+        Ok(Queue {
+            on_full: Some(on_full),
+            ..Self::new(max_size)?
+        })
+    }
+
+    /// Creates a new `Queue` with item type `T` without allocating, using the given item storage and queue struct.
+    ///
+    /// `storage` must be exactly `length * size_of::<T>()` bytes long, otherwise [`FreeRtosError::InvalidQueueSize`] is
+    /// returned.
+    ///
+    /// Only available if `configSUPPORT_STATIC_ALLOCATION` is enabled.
+    #[cfg(configSUPPORT_STATIC_ALLOCATION)]
+    pub fn new_static(
+        storage: &'static mut [u8],
+        queue_struct: &'static mut veecle_freertos_sys::bindings::StaticQueue_t,
+        length: UBaseType_t,
+    ) -> Result<Queue<T>, FreeRtosError> {
+        let item_size = size_of::<T>();
+        if (length as usize).checked_mul(item_size) != Some(storage.len()) {
+            return Err(FreeRtosError::InvalidQueueSize);
+        }
+
+        // FreeRTOS requires the storage to be null if, and only if, the items are zero-sized.
+        let storage = if item_size == 0 {
+            ptr::null_mut()
+        } else {
+            storage.as_mut_ptr()
+        };
+
+        // SAFETY:
+        // `storage` and `queue_struct` are exclusively borrowed forever, so nothing else can access them while the
+        // queue uses them. The storage has room for `length` items of `item_size`, checked above. The NULL result from
+        // `shim_xQueueCreateStatic` is captured and converted into a Rust error.
+        let handle = unsafe {
+            veecle_freertos_sys::bindings::shim_xQueueCreateStatic(
+                length,
+                item_size as UBaseType_t,
+                storage,
+                queue_struct,
+            )
+        };
+
+        if handle.is_null() {
+            return Err(FreeRtosError::InvalidQueueSize);
+        }
+
+        Ok(Queue {
+            handle,
+            capacity: length,
+            on_full: None,
             item_type: PhantomData,
         })
     }
@@ -80,6 +161,10 @@ where
     pub unsafe fn from_raw_handle(handle: QueueHandle_t) -> Self {
         Self {
             handle,
+            // SAFETY:
+            // The caller guarantees `handle` to be a valid queue handle.
+            capacity: unsafe { uxQueueGetQueueLength(handle) },
+            on_full: None,
             item_type: PhantomData,
         }
     }
@@ -90,8 +175,20 @@ where
         self.handle
     }
 
+    /// Invokes the callback passed to [`new_with_on_full`](Self::new_with_on_full), if any.
+    fn notify_full(&self) {
+        if let Some(on_full) = self.on_full {
+            on_full();
+        }
+    }
+
     /// Sends an item to the end of the queue. Waits for the queue to have empty space for it.
+    ///
+    /// # Panics
+    ///
+    /// In debug builds, if called with a non-zero `max_wait` while the scheduler is suspended.
     pub fn send(&self, item: T, max_wait: Duration) -> Result<(), T> {
+        scheduler::debug_assert_may_block(max_wait);
         let item = ManuallyDrop::new(item);
         // SAFETY:
         // Our handle is always a valid undeleted queue handle.
@@ -103,10 +200,100 @@ where
         {
             Ok(())
         } else {
+            self.notify_full();
             Err(ManuallyDrop::into_inner(item))
         }
     }
 
+    /// Sends an item to the end of the queue, waiting for empty space until `deadline`.
+    ///
+    /// Unlike [`send`](Self::send) with a fixed `max_wait`, retrying with the same deadline doesn't restart the
+    /// timeout, so repeated calls share one overall budget. Returns the item if it could not be sent before the
+    /// deadline, without waiting if the deadline has passed.
+    pub fn send_timeout_remaining(&self, item: T, deadline: Instant) -> Result<(), T> {
+        self.send(item, deadline.remaining())
+    }
+
+    /// Sends an item to the end of the queue without waiting, removing the oldest item first if the queue is full.
+    ///
+    /// This gives lossy ring-buffer semantics, e.g. for telemetry where only the freshest data matters. The evicted
+    /// item is returned so the caller decides how to drop it. Other tasks can't interleave as the scheduler is
+    /// suspended meanwhile, the item is only returned as error if an interrupt filled the queue again.
+    pub fn send_overwriting_oldest(&self, item: T) -> Result<Option<T>, T> {
+        let (evicted, result) = scheduler::with_scheduler_suspended(|| {
+            let evicted = if self.spaces_available() == 0 {
+                self.receive(Duration::zero()).ok()
+            } else {
+                None
+            };
+            (evicted, self.send(item, Duration::zero()))
+        });
+
+        // The evicted item is only returned, and possibly dropped, after resuming the scheduler.
+        match result {
+            Ok(()) => Ok(evicted),
+            Err(item) => Err(item),
+        }
+    }
+
+    /// Sends all `items` to the end of the queue, waiting up to `max_wait` for empty space for each of them.
+    ///
+    /// Stops at the first item that could not be sent and returns it together with the remaining, not yet consumed,
+    /// items. Items sent before the failure stay in the queue.
+    ///
+    /// This is also the way to seed a queue from an iterator: `Queue` doesn't implement [`Extend`], as it couldn't
+    /// report the unsent items.
+    pub fn send_iter<I>(&self, items: I, max_wait: Duration) -> Result<(), (T, I::IntoIter)>
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let mut items = items.into_iter();
+        for item in items.by_ref() {
+            if let Err(item) = self.send(item, max_wait) {
+                return Err((item, items));
+            }
+        }
+        Ok(())
+    }
+
+    /// Copies an item to the end of the queue. Waits for the queue to have empty space for it.
+    ///
+    /// Returns whether the item was sent. Unlike [`send`](Self::send), the item doesn't need to be recovered from the
+    /// error to retry sending it.
+    ///
+    /// # Panics
+    ///
+    /// In debug builds, if called with a non-zero `max_wait` while the scheduler is suspended.
+    pub fn send_ref(&self, item: &T, max_wait: Duration) -> bool
+    where
+        T: Copy,
+    {
+        scheduler::debug_assert_may_block(max_wait);
+        // SAFETY:
+        // Our handle is always a valid undeleted queue handle.
+        // The queue copies the value pointed to by `pvItemToQueue`, which is fine as `T` is `Copy`.
+        if unsafe {
+            shim_xQueueSendToBack(self.handle, ptr::from_ref(item).cast(), max_wait.ticks())
+        } == pdTRUE()
+        {
+            true
+        } else {
+            self.notify_full();
+            false
+        }
+    }
+
+    /// Sends an item to the end of the queue, like [`send`](Self::send), returning a [`FreeRtosError`] along with the
+    /// item on failure.
+    ///
+    /// FreeRTOS only fails a send if the queue stays full for `max_wait`, so the error is always
+    /// [`FreeRtosError::QueueFull`]. Unlike [`send`](Self::send), the error can be propagated with `?` once the item
+    /// was recovered.
+    pub fn send_with_error(&self, item: T, max_wait: Duration) -> Result<(), (T, FreeRtosError)> {
+        self.send(item, max_wait)
+            .map_err(|item| (item, FreeRtosError::QueueFull))
+    }
+
     /// Sends an item to the end of the queue, from an interrupt.
     pub fn send_from_isr(&self, context: &mut InterruptContext, item: T) -> Result<(), T> {
         let item = ManuallyDrop::new(item);
@@ -115,39 +302,79 @@ where
         // `xQueue` is correct. The value pointed by `pvItemToQueue` is owned by the current function, ensuring
         // it exists while `shim_xQueueSendToBackFromISR` is executed.
         // To avoid double-dropping, the `item` is wrapped in `ManuallyDrop`.
-        if unsafe {
+        // `woken` points to a flag owned by `run_operation`, valid for the duration of the call.
+        if context.run_operation(|woken| unsafe {
             veecle_freertos_sys::bindings::shim_xQueueSendToBackFromISR(
                 self.handle,
                 (&raw const *item).cast(),
-                context.get_task_field_mut(),
+                woken,
             )
-        } == pdTRUE()
-        {
+        }) {
             Ok(())
         } else {
+            self.notify_full();
             Err(ManuallyDrop::into_inner(item))
         }
     }
 
     /// Waits for an item to be available on the queue.
+    ///
+    /// # Panics
+    ///
+    /// In debug builds, if called with a non-zero `max_wait` while the scheduler is suspended.
     pub fn receive(&self, max_wait: Duration) -> Result<T, FreeRtosError> {
         let mut buffer = MaybeUninit::<T>::uninit();
 
+        self.receive_into(&mut buffer, max_wait)?;
+
+        // SAFETY:
+        // `receive_into` only returns `Ok` if an item has been copied into the buffer, allowing us to assume it has
+        // been initialized.
+        Ok(unsafe { buffer.assume_init() })
+    }
+
+    /// Waits for an item to be available on the queue until `deadline`.
+    ///
+    /// See [`send_timeout_remaining`](Self::send_timeout_remaining).
+    pub fn receive_timeout_remaining(&self, deadline: Instant) -> Result<T, FreeRtosError> {
+        self.receive(deadline.remaining())
+    }
+
+    /// Waits for an item to be available on the queue and copies it directly into `out`.
+    ///
+    /// Unlike [`receive`](Self::receive) this avoids moving the item out of an intermediate buffer, which matters for
+    /// large items. On success `out` is initialized and owns the item, it is the caller's responsibility to drop it
+    /// (e.g. via [`MaybeUninit::assume_init_drop`]). On failure `out` is left unchanged.
+    ///
+    /// # Panics
+    ///
+    /// In debug builds, if called with a non-zero `max_wait` while the scheduler is suspended.
+    pub fn receive_into(
+        &self,
+        out: &mut MaybeUninit<T>,
+        max_wait: Duration,
+    ) -> Result<(), FreeRtosError> {
+        scheduler::debug_assert_may_block(max_wait);
         // SAFETY:
         // The queue, and therefore its handle, are created during the construction of Self, ensuring the argument
-        // `xQueue` is correct. The buffer is created right before this call, ensuring its pointer to be valid.
-        if unsafe { shim_xQueueReceive(self.handle, buffer.as_mut_ptr().cast(), max_wait.ticks()) }
+        // `xQueue` is correct. `out` is a valid buffer for one item, it is only written if an item is received.
+        if unsafe { shim_xQueueReceive(self.handle, out.as_mut_ptr().cast(), max_wait.ticks()) }
             == pdTRUE()
         {
-            // SAFETY:
-            // It is ensured by `xQueueReceive` that pdTRUE is returned if, and only if, a value has been copied into
-            // the buffer, allowing us to assume it has been initialized.
-            Ok(unsafe { buffer.assume_init() })
+            Ok(())
         } else {
             Err(FreeRtosError::QueueReceiveTimeout)
         }
     }
 
+    /// Returns an iterator receiving the items currently in the queue.
+    ///
+    /// The iterator never blocks, it ends at the first receive that finds the queue empty. Items sent while iterating
+    /// are yielded as well.
+    pub fn drain(&self) -> impl Iterator<Item = T> + '_ {
+        core::iter::from_fn(|| self.receive(Duration::zero()).ok())
+    }
+
     /// Returns the number of messages waiting in the queue.
     pub fn messages_waiting(&self) -> UBaseType_t {
         // SAFETY:
@@ -156,6 +383,37 @@ where
         unsafe { uxQueueMessagesWaiting(self.handle) }
     }
 
+    /// Adds the queue to the queue registry under `name`, so kernel-aware debuggers and trace tools show it by name.
+    ///
+    /// Registering an already registered queue updates its name. The registry holds `configQUEUE_REGISTRY_SIZE`
+    /// queues, further queues are silently not registered. The queue is removed from the registry when it is deleted.
+    #[cfg(configQUEUE_REGISTRY_SIZE)]
+    pub fn set_registry_name(&self, name: &'static CStr) {
+        // SAFETY:
+        // The queue, and therefore its handle, are created during the construction of Self, ensuring the argument
+        // `xQueue` is correct. The registry keeps the name pointer, which is valid for the whole program.
+        unsafe { vQueueAddToRegistry(self.handle, name.as_ptr()) }
+    }
+
+    /// Returns the name the queue was registered with via [`set_registry_name`](Self::set_registry_name).
+    #[cfg(configQUEUE_REGISTRY_SIZE)]
+    pub fn registry_name(&self) -> Option<&'static CStr> {
+        // SAFETY:
+        // The queue, and therefore its handle, are created during the construction of Self, ensuring the argument
+        // `xQueue` is correct.
+        let name = unsafe { pcQueueGetName(self.handle) };
+
+        // SAFETY:
+        // The registry keeps the name pointer, so FreeRTOS requires registered names to stay valid as long as the queue
+        // is registered. `set_registry_name` only registers `&'static CStr`s.
+        (!name.is_null()).then(|| unsafe { CStr::from_ptr(name) })
+    }
+
+    /// Returns the maximum number of items the queue can hold.
+    pub fn capacity(&self) -> UBaseType_t {
+        self.capacity
+    }
+
     /// Returns the number of spaces available in the queue.
     pub fn spaces_available(&self) -> UBaseType_t {
         // SAFETY:
@@ -169,14 +427,205 @@ impl<T> Clone for Queue<T> {
     fn clone(&self) -> Self {
         Self {
             handle: self.handle,
+            capacity: self.capacity,
+            on_full: self.on_full,
             item_type: self.item_type,
         }
     }
 }
 
+/// A blocking queue of boxed items, only moving the pointer through FreeRTOS.
+///
+/// Sending a large `T` through a [`Queue<T>`] copies the whole item into the queue storage and out again. This queue
+/// stores the pointer of a [`Box<T>`] instead, ownership moves with it and the receiver reconstructs the `Box`. Items
+/// still in the queue when the last handle is dropped are leaked, like items of a [`Queue`].
+#[derive(Debug)]
+pub struct PointerQueue<T> {
+    queue: Queue<Box<T>>,
+}
+
+impl<T> PointerQueue<T>
+where
+    T: Send + 'static,
+{
+    /// Creates a new queue holding up to `max_size` boxed items.
+    pub fn new(max_size: UBaseType_t) -> Result<Self, FreeRtosError> {
+        Ok(Self {
+            queue: Queue::new(max_size)?,
+        })
+    }
+
+    /// Sends a boxed item to the end of the queue. Waits for the queue to have empty space for it.
+    ///
+    /// Returns the item if it could not be sent.
+    pub fn send_boxed(&self, item: Box<T>, max_wait: Duration) -> Result<(), Box<T>> {
+        self.queue.send(item, max_wait)
+    }
+
+    /// Sends a boxed item to the end of the queue from an interrupt.
+    pub fn send_boxed_from_isr(
+        &self,
+        context: &mut InterruptContext,
+        item: Box<T>,
+    ) -> Result<(), Box<T>> {
+        self.queue.send_from_isr(context, item)
+    }
+
+    /// Waits for a boxed item to be available on the queue.
+    pub fn receive_boxed(&self, max_wait: Duration) -> Result<Box<T>, FreeRtosError> {
+        self.queue.receive(max_wait)
+    }
+
+    /// Returns the number of items waiting in the queue.
+    pub fn messages_waiting(&self) -> UBaseType_t {
+        self.queue.messages_waiting()
+    }
+}
+
+impl<T> Clone for PointerQueue<T> {
+    fn clone(&self) -> Self {
+        Self {
+            queue: self.queue.clone(),
+        }
+    }
+}
+
+/// A blocking queue of fixed-size byte frames, for C interop queues carrying raw `[u8; N]` buffers.
+///
+/// Frames shorter than `N` bytes are padded with zeros, see [`send_bytes`](Self::send_bytes).
+#[derive(Debug)]
+pub struct ByteQueue<const N: usize> {
+    queue: Queue<[u8; N]>,
+}
+
+impl<const N: usize> ByteQueue<N> {
+    /// Creates a new queue holding up to `max_size` frames of `N` bytes.
+    pub fn new(max_size: UBaseType_t) -> Result<Self, FreeRtosError> {
+        Ok(Self {
+            queue: Queue::new(max_size)?,
+        })
+    }
+
+    /// Sends `bytes` as a frame to the end of the queue, padding it with zeros to `N` bytes. Waits for the queue to
+    /// have empty space for it.
+    ///
+    /// Returns [`FreeRtosError::FrameTooLong`] if `bytes` is longer than `N` and [`FreeRtosError::QueueFull`] if the
+    /// queue is still full after `max_wait`.
+    pub fn send_bytes(&self, bytes: &[u8], max_wait: Duration) -> Result<(), FreeRtosError> {
+        let mut frame = [0; N];
+        frame
+            .get_mut(..bytes.len())
+            .ok_or(FreeRtosError::FrameTooLong)?
+            .copy_from_slice(bytes);
+
+        self.queue
+            .send(frame, max_wait)
+            .map_err(|_| FreeRtosError::QueueFull)
+    }
+
+    /// Waits for a frame to be available on the queue.
+    pub fn recv_bytes(&self, max_wait: Duration) -> Result<[u8; N], FreeRtosError> {
+        self.queue.receive(max_wait)
+    }
+
+    /// Returns the number of frames waiting in the queue.
+    pub fn messages_waiting(&self) -> UBaseType_t {
+        self.queue.messages_waiting()
+    }
+}
+
+impl<const N: usize> Clone for ByteQueue<N> {
+    fn clone(&self) -> Self {
+        Self {
+            queue: self.queue.clone(),
+        }
+    }
+}
+
+/// The sending half of a blocking channel created with [`sync_channel`].
+///
+/// Clones send to the same queue, so there may be many producers.
+#[derive(Debug)]
+pub struct SyncSender<T>(Queue<T>);
+
+impl<T> SyncSender<T>
+where
+    T: Send + Sized + 'static,
+{
+    /// Sends an item to the end of the queue. Waits for the queue to have empty space for it.
+    ///
+    /// Returns the item if it could not be sent within `timeout`.
+    pub fn send(&self, item: T, timeout: Duration) -> Result<(), T> {
+        self.0.send(item, timeout)
+    }
+
+    /// Sends an item to the end of the queue from an interrupt.
+    pub fn send_from_isr(&self, context: &mut InterruptContext, item: T) -> Result<(), T> {
+        self.0.send_from_isr(context, item)
+    }
+
+    /// Returns the number of messages waiting in the queue.
+    pub fn messages_waiting(&self) -> UBaseType_t {
+        self.0.messages_waiting()
+    }
+}
+
+impl<T> Clone for SyncSender<T> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+/// The receiving half of a blocking channel created with [`sync_channel`].
+///
+/// Clones receive from the same queue, each item is received by only one of them.
+#[derive(Debug)]
+pub struct SyncReceiver<T>(Queue<T>);
+
+impl<T> SyncReceiver<T>
+where
+    T: Send + Sized + 'static,
+{
+    /// Waits for an item to be available on the queue.
+    ///
+    /// Returns an error if no item is available within `timeout`.
+    pub fn recv(&self, timeout: Duration) -> Result<T, FreeRtosError> {
+        self.0.receive(timeout)
+    }
+
+    /// Returns the number of messages waiting in the queue.
+    pub fn messages_waiting(&self) -> UBaseType_t {
+        self.0.messages_waiting()
+    }
+}
+
+impl<T> Clone for SyncReceiver<T> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+/// Creates a [`SyncSender`] [`SyncReceiver`] pair for a blocking queue holding up to `max_size` items.
+///
+/// Unlike a cloned [`Queue`], the halves make the producer and consumer roles explicit. Unlike [`channel`], the
+/// channel is not closed when one side is dropped.
+pub fn sync_channel<T>(
+    max_size: UBaseType_t,
+) -> Result<(SyncSender<T>, SyncReceiver<T>), FreeRtosError>
+where
+    T: Send + Sized + 'static,
+{
+    let queue = Queue::new(max_size)?;
+
+    Ok((SyncSender(queue.clone()), SyncReceiver(queue)))
+}
+
 /// An asynchronous queue with a finite size. For a purely blocking queue, see [`Queue`].
 ///
-/// The items are owned by the queue and move ownership when sending.
+/// The items are owned by the queue and move ownership when sending. Items still waiting when the last sender and
+/// receiver are dropped are dropped with the queue. With the `std` feature, an item panicking on drop doesn't prevent
+/// the remaining items and the FreeRTOS queue from being freed, the panic is resumed afterwards. Without it, the
+/// panic unwinds out of the drop and leaks them.
 ///
 /// ## Usage in FFIs
 ///
@@ -189,8 +638,13 @@ impl<T> Clone for Queue<T> {
 /// attribute.
 #[derive(Debug)]
 struct AsyncQueue<T> {
-    send_waker: AtomicWaker,
+    /// Wakers of all pending senders, there may be many as [`AsyncQueueSender`] is [`Clone`].
+    send_waker: WakerSet,
     receive_waker: AtomicWaker,
+    /// Number of live [`AsyncQueueSender`]s.
+    senders: AtomicUsize,
+    /// Number of live [`AsyncQueueReceiver`]s.
+    receivers: AtomicUsize,
     queue: Queue<T>,
 }
 
@@ -201,8 +655,10 @@ where
     /// Creates a new `AsyncQueue` capable of holding `length` items of type `T` via dynamic memory allocation.
     pub fn new(length: UBaseType_t) -> Result<Self, FreeRtosError> {
         Ok(AsyncQueue {
-            send_waker: AtomicWaker::default(),
+            send_waker: WakerSet::new(),
             receive_waker: AtomicWaker::default(),
+            senders: AtomicUsize::new(1),
+            receivers: AtomicUsize::new(1),
             queue: Queue::new(length)?,
         })
     }
@@ -212,16 +668,63 @@ where
     pub fn messages_waiting(&self) -> UBaseType_t {
         self.queue.messages_waiting()
     }
+
+    /// Returns `true` if all senders have been dropped.
+    #[inline]
+    fn is_sender_closed(&self) -> bool {
+        self.senders.load(Acquire) == 0
+    }
+
+    /// Returns `true` if all receivers have been dropped.
+    #[inline]
+    fn is_receiver_closed(&self) -> bool {
+        self.receivers.load(Acquire) == 0
+    }
 }
 
 impl<T> Drop for AsyncQueue<T> {
     fn drop(&mut self) {
+        // The last sender and receiver are gone, drop the items still waiting instead of leaking them.
+        #[cfg(feature = "std")]
+        let mut panic_payload = None;
+        loop {
+            let mut item = MaybeUninit::<T>::uninit();
+            // SAFETY:
+            // The queue, and therefore its handle, are created during the construction of Self, ensuring the argument
+            // `xQueue` is correct. `item` is a valid buffer for one item, it is only written if an item is received.
+            if unsafe { shim_xQueueReceive(self.queue.handle, item.as_mut_ptr().cast(), 0) }
+                != pdTRUE()
+            {
+                break;
+            }
+            // SAFETY: The receive succeeded, so `item` holds an item moved out of the queue.
+            let item = unsafe { item.assume_init() };
+
+            // A panicking item must not keep the remaining items and the queue from being freed.
+            #[cfg(feature = "std")]
+            if let Err(payload) =
+                std::panic::catch_unwind(core::panic::AssertUnwindSafe(|| drop(item)))
+            {
+                panic_payload.get_or_insert(payload);
+            }
+            #[cfg(not(feature = "std"))]
+            drop(item);
+        }
+
         // SAFETY:
         // The queue, and therefore its handle, are created during the construction of Self, ensuring the argument
         // `xQueue` is correct.
         unsafe {
             vQueueDelete(self.queue.handle);
         }
+
+        // Resuming while already unwinding would abort, the item panic is dropped in that case.
+        #[cfg(feature = "std")]
+        if let Some(payload) = panic_payload
+            && !std::thread::panicking()
+        {
+            std::panic::resume_unwind(payload);
+        }
     }
 }
 
@@ -230,6 +733,11 @@ impl<T> Drop for AsyncQueue<T> {
 /// For a purely blocking queue, see [`Queue`].
 ///
 /// The items are owned by the queue and move ownership when sending.
+///
+/// Cloning a sender allows multiple tasks to feed the same [`AsyncQueueReceiver`]. The channel is only closed for the
+/// receiver once all clones have been dropped.
+///
+/// Once the [`AsyncQueueReceiver`] has been dropped, all sending operations fail and hand the item back.
 #[derive(Debug)]
 pub struct AsyncQueueSender<T>(Arc<AsyncQueue<T>>);
 
@@ -243,12 +751,27 @@ where
         self.0.messages_waiting()
     }
 
+    /// Returns `true` if the [`AsyncQueueReceiver`] has been dropped.
+    ///
+    /// Once closed, a channel stays closed.
+    #[inline]
+    pub fn is_closed(&self) -> bool {
+        self.0.is_receiver_closed()
+    }
+
     /// Sends an item to the end of the queue.
     ///
     /// Waits for the queue to have empty space for up to `max_wait`. If `max_wait` is 0 and the queue is full,
     /// this function returns immediately.
+    ///
+    /// Returns the item if the [`AsyncQueueReceiver`] has been dropped. A receiver dropped while this function is
+    /// blocked is only observed after `max_wait`.
     #[inline]
     pub fn send_blocking(&mut self, item: T, max_wait: Duration) -> Result<(), T> {
+        if self.is_closed() {
+            return Err(item);
+        }
+
         let result = self.0.queue.send(item, max_wait);
 
         if result.is_ok() {
@@ -259,8 +782,14 @@ where
     }
 
     /// Sends an item to the end of the queue, from an interrupt.
+    ///
+    /// Returns the item if the [`AsyncQueueReceiver`] has been dropped.
     #[inline]
     pub fn send_from_isr(&mut self, context: &mut InterruptContext, item: T) -> Result<(), T> {
+        if self.is_closed() {
+            return Err(item);
+        }
+
         let result = self.0.queue.send_from_isr(context, item);
 
         if result.is_ok() {
@@ -270,41 +799,69 @@ where
         result
     }
 
-    /// Resolves when at least one space is available in the queue.
-    async fn poll_ready(&mut self) {
+    /// Asynchronous version of [`send_blocking`](Self::send_blocking).
+    ///
+    /// This function stays pending until the queue has space for the item.
+    ///
+    /// Returns the item if the [`AsyncQueueReceiver`] has been dropped.
+    pub async fn send(&mut self, item: T) -> Result<(), T> {
+        let mut item = Some(item);
+
         poll_fn(|cx| {
-            self.0.send_waker.register(cx.waker());
+            let Some(mut value) = item.take() else {
+                unreachable!("polled after completion");
+            };
+
+            for attempt in 0..2 {
+                if self.is_closed() {
+                    return Poll::Ready(Err(value));
+                }
 
-            let result = self.0.queue.spaces_available();
+                match self.0.queue.send(value, Duration::zero()) {
+                    Ok(()) => {
+                        self.0.receive_waker.wake();
+                        return Poll::Ready(Ok(()));
+                    }
+                    Err(returned) => value = returned,
+                }
 
-            if result == 0 {
-                Poll::Pending
-            } else {
-                Poll::Ready(())
+                // Try again after registering the waker, so that space freed up in between isn't missed. Other
+                // senders may take the free space at any point, so sending has to be attempted rather than checking
+                // for free space.
+                if attempt == 0 {
+                    self.0.send_waker.register(cx.waker());
+                }
             }
+
+            item = Some(value);
+            Poll::Pending
         })
-        .await;
+        .await
     }
+}
 
-    /// Asynchronous version of [`send_blocking`](Self::send_blocking).
-    ///
-    /// This function stays pending until the queue has space for the item.
-    pub async fn send(&mut self, item: T) {
-        self.poll_ready().await;
-
-        // `T` doesn't implement `Debug`, so we cannot `expect()`.
-        if self.0.queue.send(item, Duration::zero()).is_err() {
-            // `poll_ready` resolving guarantees a free slot in the queue, so `send` will never fail.
-            unreachable!("sending failed unexpectedly");
-        };
+impl<T> Clone for AsyncQueueSender<T> {
+    fn clone(&self) -> Self {
+        self.0.senders.fetch_add(1, AcqRel);
+        Self(Arc::clone(&self.0))
+    }
+}
 
-        self.0.receive_waker.wake();
+impl<T> Drop for AsyncQueueSender<T> {
+    fn drop(&mut self) {
+        if self.0.senders.fetch_sub(1, AcqRel) == 1 {
+            // The last sender is gone, a pending receiver needs to observe the closed channel.
+            self.0.receive_waker.wake();
+        }
     }
 }
 
 /// An asynchronous queue receiver. Can be used to receive data from an [`AsyncQueueSender`]. Use [`channel`] to create.
 ///
 /// For a purely blocking queue, see [`Queue`].
+///
+/// Once all [`AsyncQueueSender`]s have been dropped, the items still waiting in the queue can be received, after which
+/// receiving reports the channel as closed.
 #[derive(Debug)]
 pub struct AsyncQueueReceiver<T>(Arc<AsyncQueue<T>>);
 
@@ -318,14 +875,35 @@ where
         self.0.messages_waiting()
     }
 
+    /// Returns `true` if all [`AsyncQueueSender`]s have been dropped.
+    ///
+    /// Items sent before the senders were dropped may still be waiting in the queue.
+    #[inline]
+    pub fn is_closed(&self) -> bool {
+        self.0.is_sender_closed()
+    }
+
     /// Waits for an item to be available on the queue.
     ///
     /// Returns an item if available and an error if no item is available after `max_wait`.
+    ///
+    /// Returns [`FreeRtosError::QueueClosed`] without waiting if all [`AsyncQueueSender`]s have been dropped and the
+    /// queue is empty. Senders dropped while this function is blocked are only observed after `max_wait`.
     pub fn receive_blocking(&mut self, max_wait: Duration) -> Result<T, FreeRtosError> {
+        let max_wait = if self.is_closed() {
+            Duration::zero()
+        } else {
+            max_wait
+        };
+
         let result = self.0.queue.receive(max_wait);
 
-        if result.is_ok() {
-            self.0.send_waker.wake();
+        match result {
+            Ok(_) => self.0.send_waker.wake(),
+            Err(_) if self.is_closed() && self.0.queue.messages_waiting() == 0 => {
+                return Err(FreeRtosError::QueueClosed);
+            }
+            Err(_) => {}
         }
 
         result
@@ -334,23 +912,187 @@ where
     /// Asynchronous version of [`receive_blocking`](Self::receive_blocking).
     ///
     /// This function stays pending until the queue has received an item.
-    pub async fn receive(&mut self) -> T {
-        poll_fn(|cx| {
-            let result = self.0.queue.receive(Duration::zero());
+    ///
+    /// Returns `None` once all [`AsyncQueueSender`]s have been dropped and the queue is empty.
+    pub async fn receive(&mut self) -> Option<T> {
+        poll_fn(|cx| self.poll_receive(cx)).await
+    }
 
-            if let Ok(item) = result {
-                self.0.send_waker.wake();
-                Poll::Ready(item)
-            } else {
-                self.0.receive_waker.register(cx.waker());
-                Poll::Pending
-            }
-        })
-        .await
+    /// Attempts to receive an item, registering the current task to be woken when an item arrives or the channel
+    /// closes.
+    fn poll_receive(&mut self, cx: &mut Context<'_>) -> Poll<Option<T>> {
+        if let Ok(item) = self.0.queue.receive(Duration::zero()) {
+            self.0.send_waker.wake();
+            return Poll::Ready(Some(item));
+        }
+
+        self.0.receive_waker.register(cx.waker());
+
+        // Check again after registering the waker, so that items sent and senders dropped in between aren't missed.
+        // The closed state has to be observed before receiving, otherwise the last item of a sender dropped after
+        // the receive attempt would be missed.
+        let closed = self.is_closed();
+
+        if let Ok(item) = self.0.queue.receive(Duration::zero()) {
+            self.0.send_waker.wake();
+            Poll::Ready(Some(item))
+        } else if closed {
+            Poll::Ready(None)
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+impl<T> Drop for AsyncQueueReceiver<T> {
+    fn drop(&mut self) {
+        if self.0.receivers.fetch_sub(1, AcqRel) == 1 {
+            // The receiver is gone, pending senders need to observe the closed channel.
+            self.0.send_waker.wake();
+        }
     }
 }
 
+/// The item of whichever receiver passed to [`select2`] produced first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Either<A, B> {
+    /// An item received from the first receiver.
+    A(A),
+    /// An item received from the second receiver.
+    B(B),
+}
+
+/// Waits for an item from either of two receivers.
+///
+/// If both receivers have an item available, the item from `a` is returned. A closed receiver is ignored while the
+/// other one is still open, `None` is returned once both are closed and empty.
+pub async fn select2<A, B>(
+    a: &mut AsyncQueueReceiver<A>,
+    b: &mut AsyncQueueReceiver<B>,
+) -> Option<Either<A, B>>
+where
+    A: Send + Sized + 'static,
+    B: Send + Sized + 'static,
+{
+    poll_fn(|cx| {
+        // Both receivers are always polled, so that both wakers are registered when pending.
+        let a_closed = match a.poll_receive(cx) {
+            Poll::Ready(Some(item)) => return Poll::Ready(Some(Either::A(item))),
+            Poll::Ready(None) => true,
+            Poll::Pending => false,
+        };
+
+        match b.poll_receive(cx) {
+            Poll::Ready(Some(item)) => Poll::Ready(Some(Either::B(item))),
+            Poll::Ready(None) if a_closed => Poll::Ready(None),
+            Poll::Ready(None) | Poll::Pending => Poll::Pending,
+        }
+    })
+    .await
+}
+
+/// Waits for the first of multiple futures to complete, preferring earlier branches.
+///
+/// Branches are polled in declaration order every time, so if multiple futures are ready the first one wins. This
+/// allows higher-priority sources, like a shutdown signal, to take precedence over data sources. Unlike [`select2`],
+/// any future can be used.
+///
+/// Each branch has the form `pattern = future => body`. Once a future completes, all futures are dropped and the body
+/// of its branch is evaluated with the bindings of `pattern`. The macro returns the value of the body, so all bodies
+/// must have the same type. Bodies are evaluated in the surrounding scope: `return`, `?`, `break` and `continue` apply
+/// to the surrounding function or loop, and bodies may use what the futures borrowed.
+///
+/// A branch whose output doesn't match its pattern is disabled and the remaining branches keep being polled, like a
+/// closed channel in a `Some(value) = receiver.receive()` branch. Panics if all branches are disabled. The pattern is
+/// checked against a reference to the output first, so it can't contain `mut` or `ref` bindings.
+///
+/// ```ignore
+/// loop {
+///     select_biased! {
+///         _ = shutdown.receive() => break,
+///         // Disabled once `data` is closed, only waiting for `shutdown` from then on.
+///         Some(value) = data.receive() => handle(value),
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! select_biased {
+    // All branches have hidden identifiers for their future, enabled flag and output, generate the select.
+    (@branches [$((
+        $future:ident, $enabled:ident, $output:ident, $pattern:pat, $fut:expr, $body:expr
+    ))+]) => {{
+        // The futures are dropped at the end of this block, before a body is evaluated.
+        let ($($output,)+) = {
+            $(
+                let mut $future = ::core::pin::pin!($fut);
+                let mut $enabled = true;
+                let mut $output = ::core::option::Option::None;
+            )+
+
+            loop {
+                ::core::future::poll_fn(|cx| {
+                    $(
+                        if $enabled {
+                            if let ::core::task::Poll::Ready(value) =
+                                ::core::future::Future::poll($future.as_mut(), cx)
+                            {
+                                $output = ::core::option::Option::Some(value);
+                                return ::core::task::Poll::Ready(());
+                            }
+                        }
+                    )+
+                    ::core::task::Poll::Pending
+                })
+                .await;
+
+                $(
+                    if let ::core::option::Option::Some(value) = &$output {
+                        #[allow(unused_variables, unreachable_patterns)]
+                        let matches = match value {
+                            $pattern => true,
+                            _ => false,
+                        };
+                        if !matches {
+                            $output = ::core::option::Option::None;
+                            $enabled = false;
+                        }
+                    }
+                )+
+
+                if [$($output.is_some()),+].contains(&true) {
+                    break;
+                }
+                ::core::assert!(
+                    [$($enabled),+].contains(&true),
+                    "select_biased! disabled all branches"
+                );
+            }
+
+            ($($output,)+)
+        };
+
+        $(
+            if let ::core::option::Option::Some($pattern) = $output {
+                $body
+            } else
+        )+ {
+            ::core::unreachable!("a branch completed")
+        }
+    }};
+    // Give the next branch its hidden identifiers, each recursion step creates distinct ones.
+    (@branches [$($done:tt)*] $pattern:pat = $fut:expr => $body:expr $(, $($rest:tt)*)?) => {
+        $crate::select_biased!(
+            @branches [$($done)* (future, enabled, output, $pattern, $fut, $body)] $($($rest)*)?
+        )
+    };
+    ($($branches:tt)+) => {
+        $crate::select_biased!(@branches [] $($branches)+)
+    };
+}
+
 /// Creates a [`AsyncQueueSender`] [`AsyncQueueReceiver`] pair.
+///
+/// Items still waiting when both ends are dropped are dropped with the channel.
 pub fn channel<T>(
     max_size: UBaseType_t,
 ) -> Result<(AsyncQueueSender<T>, AsyncQueueReceiver<T>), FreeRtosError>
@@ -364,15 +1106,98 @@ where
     Ok((sender, receiver))
 }
 
+/// This value was determined by trial and error and has worked consistently during tests. It is *not* derived from
+/// anything and might need to change with future versions of Rust or the crate. The bridge builders' `calibrate_stack`
+/// measures the stack a bridge actually needs.
+const BRIDGE_BASE_STACK_SIZE: StackType_t = 256;
+
+/// Default for how long bridge tasks block before checking for a shutdown request, in milliseconds.
+const BRIDGE_POLL_INTERVAL_MS: TickType_t = 100;
+
+/// Returns the default stack size of a bridge task receiving `T` and sending `U`.
+fn bridge_stack_size<T, U>() -> StackType_t {
+    // The FreeRTOS task requires memory for the received `T` and two instances of `U` to handle resending on failure.
+    let data_size = size_of::<T>() as StackType_t + size_of::<U>() as StackType_t * 2;
+
+    BRIDGE_BASE_STACK_SIZE + data_size
+}
+
+/// Factor applied to the default bridge stack size for calibration runs, leaving ample headroom.
+const BRIDGE_CALIBRATION_STACK_FACTOR: StackType_t = 4;
+
+/// Minimum margin added to the observed stack usage by a calibration, in words.
+const BRIDGE_CALIBRATION_MIN_MARGIN: StackType_t = 32;
+
+/// How long a calibration waits for an item to cross the bridge, in milliseconds.
+const BRIDGE_CALIBRATION_TIMEOUT_MS: TickType_t = 1000;
+
+/// Stack usage of a bridge task, measured by [`BlockingToAsyncQueueTaskBuilder::calibrate_stack`] or
+/// [`AsyncToBlockingQueueTaskBuilder::calibrate_stack`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BridgeStackCalibration {
+    /// Stack size the bridge task ran with, in words.
+    pub stack_size: StackType_t,
+    /// Maximum stack usage observed, in words.
+    pub used: StackType_t,
+    /// Recommended stack size, the observed usage plus a safety margin, in words.
+    pub recommended: StackType_t,
+}
+
+impl BridgeStackCalibration {
+    /// Measures the stack usage of the bridge `task` running with `stack_size` words of stack.
+    fn measure(task: &Task, stack_size: StackType_t) -> Self {
+        let used = stack_size.saturating_sub(task.get_stack_high_water_mark() as StackType_t);
+        // The usage depends on the items and code paths exercised during calibration, so leave a margin of a quarter.
+        let recommended = used + (used / 4).max(BRIDGE_CALIBRATION_MIN_MARGIN);
+
+        Self {
+            stack_size,
+            used,
+            recommended,
+        }
+    }
+}
+
+/// Handle to a bridge task created by [`BlockingToAsyncQueueTaskBuilder::create`] or
+/// [`AsyncToBlockingQueueTaskBuilder::create`].
+#[derive(Debug, Clone)]
+pub struct BridgeHandle {
+    task: Task,
+    shutdown: Arc<AtomicBool>,
+}
+
+impl BridgeHandle {
+    /// Returns the bridge task.
+    pub fn task(&self) -> &Task {
+        &self.task
+    }
+
+    /// Requests the bridge task to stop forwarding items.
+    ///
+    /// The task observes the request within one poll interval (see
+    /// [`BlockingToAsyncQueueTaskBuilder::poll_interval`]), closes its end of the asynchronous channel and suspends
+    /// itself. Items in flight when the request is observed are dropped. As task deletion is not supported, the task's
+    /// memory is not freed.
+    pub fn shutdown(&self) {
+        self.shutdown.store(true, Release);
+    }
+}
+
 /// Builder for a task that can receive items from a blocking [`Queue`] and send them to an
 /// asynchronous queue.
+///
+/// Items are converted from `T` to `U` by the function set via [`map`](Self::map), by default they are forwarded
+/// unchanged.
 #[derive(Debug)]
-pub struct BlockingToAsyncQueueTaskBuilder<T> {
+pub struct BlockingToAsyncQueueTaskBuilder<T, U = T, F = fn(T) -> U> {
     name: &'static CStr,
     queue: Queue<T>,
+    map: F,
     priority: TaskPriority,
     capacity: UBaseType_t,
-    stack_size: StackType_t,
+    stack_size: Option<StackType_t>,
+    poll_interval: Duration,
+    item_type: PhantomData<fn(T) -> U>,
 }
 
 impl<T> BlockingToAsyncQueueTaskBuilder<T>
@@ -381,22 +1206,25 @@ where
 {
     /// Creates a new queue bridge task builder.
     pub fn new(name: &'static CStr, queue: Queue<T>, capacity: UBaseType_t) -> Self {
-        // This value was determined by trial and error and has worked consistently during tests. It is *not*
-        // derived from anything and might need to change with future versions of Rust or the crate.
-        const BASE_STACK_SIZE: StackType_t = 256;
-
-        // The FreeRTOS task requires memory for two instances of T to handle resending on failure.
-        let data_size = size_of::<T>() as StackType_t * 2;
-
         Self {
             name,
             queue,
+            map: core::convert::identity,
             capacity,
             priority: TaskPriority(1),
-            stack_size: BASE_STACK_SIZE + data_size,
+            stack_size: None,
+            poll_interval: Duration::from_ms(BRIDGE_POLL_INTERVAL_MS),
+            item_type: PhantomData,
         }
     }
+}
 
+impl<T, U, F> BlockingToAsyncQueueTaskBuilder<T, U, F>
+where
+    T: Send + Sized + 'static,
+    U: Send + Sized + 'static,
+    F: Fn(T) -> U + Send + 'static,
+{
     /// Sets the priority of the FreeRTOS task.
     pub fn priority(mut self, priority: TaskPriority) -> Self {
         self.priority = priority;
@@ -404,48 +1232,138 @@ where
     }
 
     /// Sets the stack size of the FreeRTOS task.
+    ///
+    /// By default, the stack size is derived from the sizes of the items crossing the bridge.
     pub fn stack_size(mut self, stack_size: StackType_t) -> Self {
-        self.stack_size = stack_size;
+        self.stack_size = Some(stack_size);
+        self
+    }
+
+    /// Sets how long the bridge task blocks on a queue operation before checking for a
+    /// [shutdown request](BridgeHandle::shutdown).
+    ///
+    /// Shorter intervals make shutdown more responsive, at the cost of waking the task more often while the queues are
+    /// idle. Defaults to 100 ms.
+    pub fn poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
         self
     }
 
-    /// Creates the task and returns a receiver to receive items from the blocking queue in an asynchronous manner.
-    pub fn create(self) -> Result<AsyncQueueReceiver<T>, FreeRtosError> {
+    /// Converts items with `map` as they cross the bridge, producing a receiver of `V`.
+    ///
+    /// `map` runs in the bridge task, after any previously set conversion.
+    pub fn map<V>(
+        self,
+        map: impl Fn(U) -> V + Send + 'static,
+    ) -> BlockingToAsyncQueueTaskBuilder<T, V, impl Fn(T) -> V + Send + 'static>
+    where
+        V: Send + Sized + 'static,
+    {
+        let previous = self.map;
+
+        BlockingToAsyncQueueTaskBuilder {
+            name: self.name,
+            queue: self.queue,
+            map: move |item| map(previous(item)),
+            priority: self.priority,
+            capacity: self.capacity,
+            stack_size: self.stack_size,
+            poll_interval: self.poll_interval,
+            item_type: PhantomData,
+        }
+    }
+
+    /// Creates the task and returns a receiver to receive items from the blocking queue in an asynchronous manner,
+    /// along with a handle to the bridge task.
+    pub fn create(self) -> Result<(AsyncQueueReceiver<U>, BridgeHandle), FreeRtosError> {
         let (mut sender, receiver) = channel(self.capacity)?;
+        let shutdown = Arc::new(AtomicBool::new(false));
+        let task_shutdown = Arc::clone(&shutdown);
 
-        Task::new()
+        let task = Task::new()
             .name(self.name)
-            .stack_size(self.stack_size)
+            .stack_size(self.stack_size.unwrap_or_else(bridge_stack_size::<T, U>))
             .priority(self.priority)
             .start(move |_| {
-                loop {
-                    // Any non-zero delay behaves the same because after a timeout it will try again until the operation
-                    // succeeds. The longer the delay the better, since we don't want to waste
-                    // resources starting the same operation over and over, so we use the maximum
-                    // allowed timeout.
-                    let duration = Duration::max();
-
-                    if let Ok(mut data) = self.queue.receive(duration) {
+                // After a timeout the operation is retried until it succeeds. The timeout is bounded so that shutdown
+                // requests are observed.
+                let duration = self.poll_interval;
+
+                'bridge: while !task_shutdown.load(Acquire) {
+                    if let Ok(data) = self.queue.receive(duration) {
+                        let mut data = (self.map)(data);
                         while let Err(saved_data) = sender.send_blocking(data, duration) {
+                            if sender.is_closed() {
+                                park();
+                            }
+                            if task_shutdown.load(Acquire) {
+                                break 'bridge;
+                            }
                             data = saved_data;
                         }
                     }
                 }
+
+                // Closes the channel for the receiver.
+                drop(sender);
+                park();
             })?;
 
-        Ok(receiver)
+        Ok((receiver, BridgeHandle { task, shutdown }))
+    }
+
+    /// Like [`create`](Self::create), but without returning the bridge handle.
+    pub fn create_detached(self) -> Result<AsyncQueueReceiver<U>, FreeRtosError> {
+        self.create().map(|(receiver, _)| receiver)
+    }
+
+    /// Runs the bridge with `items` to measure the stack usage of its task, intended for development only.
+    ///
+    /// The bridge task runs with the configured stack size, or a multiple of the default, while each item is sent to
+    /// the blocking queue and received from the asynchronous end. The task is shut down afterwards, as task deletion
+    /// is not supported its memory is not freed. Use the [recommended](BridgeStackCalibration::recommended) size with
+    /// [`stack_size`](Self::stack_size).
+    ///
+    /// Must be called from a task, fails if an item does not cross the bridge within one second.
+    pub fn calibrate_stack(
+        self,
+        items: impl IntoIterator<Item = T>,
+    ) -> Result<BridgeStackCalibration, FreeRtosError> {
+        let stack_size = self
+            .stack_size
+            .unwrap_or_else(|| bridge_stack_size::<T, U>() * BRIDGE_CALIBRATION_STACK_FACTOR);
+        let queue = self.queue.clone();
+        let timeout = Duration::from_ms(BRIDGE_CALIBRATION_TIMEOUT_MS);
+
+        let (mut receiver, handle) = self.stack_size(stack_size).create()?;
+        let result = items.into_iter().try_for_each(|item| {
+            queue
+                .send(item, timeout)
+                .map_err(|_| FreeRtosError::QueueSendTimeout)?;
+            receiver.receive_blocking(timeout).map(drop)
+        });
+        let calibration = BridgeStackCalibration::measure(handle.task(), stack_size);
+        handle.shutdown();
+
+        result.map(|()| calibration)
     }
 }
 
 /// Builder for a task that can receive items from an asynchronous queue and send them to a
 /// blocking [`Queue`].
+///
+/// Items are converted from `U` to `T` by the function set via [`map`](Self::map), by default they are forwarded
+/// unchanged.
 #[derive(Debug)]
-pub struct AsyncToBlockingQueueTaskBuilder<T> {
+pub struct AsyncToBlockingQueueTaskBuilder<T, U = T, F = fn(U) -> T> {
     name: &'static CStr,
     queue: Queue<T>,
+    map: F,
     priority: TaskPriority,
     capacity: UBaseType_t,
-    stack_size: StackType_t,
+    stack_size: Option<StackType_t>,
+    poll_interval: Duration,
+    item_type: PhantomData<fn(U) -> T>,
 }
 
 impl<T> AsyncToBlockingQueueTaskBuilder<T>
@@ -454,22 +1372,25 @@ where
 {
     /// Creates a new queue bridge task builder.
     pub fn new(name: &'static CStr, queue: Queue<T>, capacity: UBaseType_t) -> Self {
-        // This value was determined by trial and error and has worked consistently during tests. It is *not*
-        // derived from anything and might need to change with future versions of Rust or the crate.
-        const BASE_STACK_SIZE: StackType_t = 256;
-
-        // The FreeRTOS task requires memory for two instances of T to handle resending on failure.
-        let data_size = size_of::<T>() as StackType_t * 2;
-
         Self {
             name,
             queue,
+            map: core::convert::identity,
             priority: TaskPriority(1),
             capacity,
-            stack_size: BASE_STACK_SIZE + data_size,
+            stack_size: None,
+            poll_interval: Duration::from_ms(BRIDGE_POLL_INTERVAL_MS),
+            item_type: PhantomData,
         }
     }
+}
 
+impl<T, U, F> AsyncToBlockingQueueTaskBuilder<T, U, F>
+where
+    T: Send + Sized + 'static,
+    U: Send + Sized + 'static,
+    F: Fn(U) -> T + Send + 'static,
+{
     /// Sets the priority of the FreeRTOS task.
     pub fn priority(mut self, priority: TaskPriority) -> Self {
         self.priority = priority;
@@ -477,35 +1398,129 @@ where
     }
 
     /// Sets the stack size of the FreeRTOS task.
+    ///
+    /// By default, the stack size is derived from the sizes of the items crossing the bridge.
     pub fn stack_size(mut self, stack_size: StackType_t) -> Self {
-        self.stack_size = stack_size;
+        self.stack_size = Some(stack_size);
+        self
+    }
+
+    /// Sets how long the bridge task blocks on a queue operation before checking for a
+    /// [shutdown request](BridgeHandle::shutdown).
+    ///
+    /// Shorter intervals make shutdown more responsive, at the cost of waking the task more often while the queues are
+    /// idle. Defaults to 100 ms.
+    pub fn poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
         self
     }
 
-    /// Creates the task and returns a sender to send items to the blocking queue in an asynchronous manner.
-    pub fn create(self) -> Result<AsyncQueueSender<T>, FreeRtosError> {
+    /// Converts items with `map` as they cross the bridge, producing a sender of `V`.
+    ///
+    /// `map` runs in the bridge task, before any previously set conversion.
+    pub fn map<V>(
+        self,
+        map: impl Fn(V) -> U + Send + 'static,
+    ) -> AsyncToBlockingQueueTaskBuilder<T, V, impl Fn(V) -> T + Send + 'static>
+    where
+        V: Send + Sized + 'static,
+    {
+        let previous = self.map;
+
+        AsyncToBlockingQueueTaskBuilder {
+            name: self.name,
+            queue: self.queue,
+            map: move |item| previous(map(item)),
+            priority: self.priority,
+            capacity: self.capacity,
+            stack_size: self.stack_size,
+            poll_interval: self.poll_interval,
+            item_type: PhantomData,
+        }
+    }
+
+    /// Creates the task and returns a sender to send items to the blocking queue in an asynchronous manner, along
+    /// with a handle to the bridge task.
+    pub fn create(self) -> Result<(AsyncQueueSender<U>, BridgeHandle), FreeRtosError> {
         let (sender, mut receiver) = channel(self.capacity)?;
+        let shutdown = Arc::new(AtomicBool::new(false));
+        let task_shutdown = Arc::clone(&shutdown);
 
-        Task::new()
+        let task = Task::new()
             .name(self.name)
-            .stack_size(self.stack_size)
+            .stack_size(self.stack_size.unwrap_or_else(bridge_stack_size::<U, T>))
             .priority(self.priority)
             .start(move |_| {
-                loop {
-                    // Any non-zero delay behaves the same because after a timeout it will try again until the operation
-                    // succeeds. The longer the delay the better, since we don't want to waste
-                    // resources starting the same operation over and over, so we use the maximum
-                    // allowed timeout.
-                    let duration = Duration::max();
-
-                    if let Ok(mut data) = receiver.receive_blocking(duration) {
-                        while let Err(saved_data) = self.queue.send(data, duration) {
-                            data = saved_data;
+                // After a timeout the operation is retried until it succeeds. The timeout is bounded so that shutdown
+                // requests are observed.
+                let duration = self.poll_interval;
+
+                'bridge: while !task_shutdown.load(Acquire) {
+                    match receiver.receive_blocking(duration) {
+                        Ok(data) => {
+                            let mut data = (self.map)(data);
+                            while let Err(saved_data) = self.queue.send(data, duration) {
+                                if task_shutdown.load(Acquire) {
+                                    break 'bridge;
+                                }
+                                data = saved_data;
+                            }
                         }
+                        Err(FreeRtosError::QueueClosed) => park(),
+                        Err(_) => {}
                     }
                 }
+
+                // Closes the channel for the senders.
+                drop(receiver);
+                park();
             })?;
 
-        Ok(sender)
+        Ok((sender, BridgeHandle { task, shutdown }))
+    }
+
+    /// Like [`create`](Self::create), but without returning the bridge handle.
+    pub fn create_detached(self) -> Result<AsyncQueueSender<U>, FreeRtosError> {
+        self.create().map(|(sender, _)| sender)
+    }
+
+    /// Runs the bridge with `items` to measure the stack usage of its task, intended for development only.
+    ///
+    /// The bridge task runs with the configured stack size, or a multiple of the default, while each item is sent to
+    /// the asynchronous end and received from the blocking queue. The task is shut down afterwards, as task deletion
+    /// is not supported its memory is not freed. Use the [recommended](BridgeStackCalibration::recommended) size with
+    /// [`stack_size`](Self::stack_size).
+    ///
+    /// Must be called from a task, fails if an item does not cross the bridge within one second.
+    pub fn calibrate_stack(
+        self,
+        items: impl IntoIterator<Item = U>,
+    ) -> Result<BridgeStackCalibration, FreeRtosError> {
+        let stack_size = self
+            .stack_size
+            .unwrap_or_else(|| bridge_stack_size::<U, T>() * BRIDGE_CALIBRATION_STACK_FACTOR);
+        let queue = self.queue.clone();
+        let timeout = Duration::from_ms(BRIDGE_CALIBRATION_TIMEOUT_MS);
+
+        let (mut sender, handle) = self.stack_size(stack_size).create()?;
+        let result = items.into_iter().try_for_each(|item| {
+            sender
+                .send_blocking(item, timeout)
+                .map_err(|_| FreeRtosError::QueueSendTimeout)?;
+            queue.receive(timeout).map(drop)
+        });
+        let calibration = BridgeStackCalibration::measure(handle.task(), stack_size);
+        handle.shutdown();
+
+        result.map(|()| calibration)
+    }
+}
+
+/// Parks a bridge task whose channel has been closed.
+///
+/// Tasks are not allowed to return, so the task suspends itself forever instead.
+fn park() -> ! {
+    loop {
+        CurrentTask::suspend();
     }
 }
@@ -0,0 +1,121 @@
+use veecle_freertos_sys::bindings::{
+    QueueHandle_t, QueueSetHandle_t, QueueSetMemberHandle_t, UBaseType_t, pdTRUE, xQueueAddToSet,
+    xQueueCreateSet, xQueueSelectFromSet,
+};
+
+use crate::units::Duration;
+use crate::{FreeRtosError, Queue};
+
+/// A set of queues and semaphores a task can block on at once.
+///
+/// [`select`](QueueSet::select) returns the member that has an item available, which then has to be read from that
+/// member. Members should only be read after being selected.
+///
+/// Dropping a [`QueueSet`] does *not* destroy the underlying FreeRTOS queue set.
+#[derive(Debug, Clone)]
+pub struct QueueSet {
+    handle: QueueSetHandle_t,
+}
+
+// SAFETY: The queue set struct only contains a pointer to the FreeRTOS resource so it is always Send.
+unsafe impl Send for QueueSet {}
+
+// SAFETY: The queue set struct only contains a pointer to the FreeRTOS resource so it is always Sync.
+unsafe impl Sync for QueueSet {}
+
+impl QueueSet {
+    /// Creates a new `QueueSet` via dynamic memory allocation.
+    ///
+    /// `event_length` must be at least the sum of the lengths of all members, semaphores count with their maximum
+    /// count. Otherwise events may be lost.
+    pub fn new(event_length: UBaseType_t) -> Result<Self, FreeRtosError> {
+        // SAFETY:
+        // No requirements on the caller. The NULL result from `xQueueCreateSet` is captured and converted into a Rust
+        // error.
+        let handle = unsafe { xQueueCreateSet(event_length) };
+
+        if handle.is_null() {
+            return Err(FreeRtosError::OutOfMemory);
+        }
+
+        Ok(QueueSet { handle })
+    }
+
+    /// Adds `queue` to the set.
+    ///
+    /// Fails with [`FreeRtosError::QueueSetAddFailed`] if the queue is not empty or already a member of a set.
+    pub fn add<T>(&self, queue: &Queue<T>) -> Result<QueueSetMember, FreeRtosError>
+    where
+        T: Send + Sized + 'static,
+    {
+        // SAFETY:
+        // `Queue` guarantees its handle to be a valid queue handle.
+        unsafe { self.add_raw(queue.raw_handle()) }
+    }
+
+    /// Adds a raw queue or semaphore handle to the set.
+    ///
+    /// Fails with [`FreeRtosError::QueueSetAddFailed`] if the member is not empty or already a member of a set.
+    ///
+    /// # Safety
+    ///
+    /// `handle` must be a valid FreeRTOS queue or semaphore handle, which must stay valid while it is a member of the
+    /// set.
+    pub unsafe fn add_raw(
+        &self,
+        handle: QueueSetMemberHandle_t,
+    ) -> Result<QueueSetMember, FreeRtosError> {
+        // SAFETY:
+        // The caller guarantees `handle` to be valid, our handle is a valid queue set handle.
+        if unsafe { xQueueAddToSet(handle, self.handle) } == pdTRUE() {
+            Ok(QueueSetMember { handle })
+        } else {
+            Err(FreeRtosError::QueueSetAddFailed)
+        }
+    }
+
+    /// Waits for up to `timeout` for any member of the set to have an item available.
+    ///
+    /// Returns the ready member, or `None` on timeout.
+    pub fn select(&self, timeout: Duration) -> Option<QueueSetMember> {
+        // SAFETY:
+        // Our handle is a valid queue set handle.
+        let handle = unsafe { xQueueSelectFromSet(self.handle, timeout.ticks()) };
+
+        (!handle.is_null()).then_some(QueueSetMember { handle })
+    }
+
+    /// Returns the raw queue set handle.
+    #[inline]
+    pub fn raw_handle(&self) -> QueueSetHandle_t {
+        self.handle
+    }
+}
+
+/// A member of a [`QueueSet`], as returned by [`QueueSet::add`] and [`QueueSet::select`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QueueSetMember {
+    handle: QueueSetMemberHandle_t,
+}
+
+// SAFETY: The queue set member struct only contains a pointer to the FreeRTOS resource so it is always Send.
+unsafe impl Send for QueueSetMember {}
+
+// SAFETY: The queue set member struct only contains a pointer to the FreeRTOS resource so it is always Sync.
+unsafe impl Sync for QueueSetMember {}
+
+impl QueueSetMember {
+    /// Returns `true` if this member is `queue`.
+    pub fn is<T>(&self, queue: &Queue<T>) -> bool
+    where
+        T: Send + Sized + 'static,
+    {
+        self.handle == queue.raw_handle()
+    }
+
+    /// Returns the raw handle of the member queue or semaphore.
+    #[inline]
+    pub fn raw_handle(&self) -> QueueHandle_t {
+        self.handle
+    }
+}
@@ -0,0 +1,41 @@
+use veecle_freertos_sys::bindings::TickType_t;
+
+use crate::scheduler;
+use crate::units::Duration;
+
+/// Allows an action at most once per interval, e.g. to throttle logging.
+///
+/// Based on the tick count, handling it wrapping around like [`Duration::between`].
+#[derive(Debug, Clone)]
+pub struct RateLimiter {
+    interval: Duration,
+    /// Tick count of the last allowed action, `None` until the first one.
+    last_allowed: Option<TickType_t>,
+}
+
+impl RateLimiter {
+    /// Creates a rate limiter allowing one action per `interval`, the first action is always allowed.
+    pub fn new(interval: Duration) -> Self {
+        Self {
+            interval,
+            last_allowed: None,
+        }
+    }
+
+    /// Returns whether at least the interval has passed since the last allowed action, recording this action if so.
+    pub fn try_acquire(&mut self) -> bool {
+        let now = scheduler::get_tick_count();
+        if let Some(last_allowed) = self.last_allowed
+            && Duration::between(last_allowed, now) < self.interval
+        {
+            return false;
+        }
+        self.last_allowed = Some(now);
+        true
+    }
+
+    /// Returns the minimum interval between allowed actions.
+    pub fn interval(&self) -> Duration {
+        self.interval
+    }
+}
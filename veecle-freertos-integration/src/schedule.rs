@@ -0,0 +1,181 @@
+//! Periodic jobs multiplexed onto a single software timer.
+
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::ffi::CStr;
+use core::fmt::{self, Debug};
+
+use veecle_freertos_sys::bindings::TickType_t;
+
+use crate::units::Duration;
+use crate::{BoxedTimerCallback, FreeRtosError, Timer, TimerHandle, scheduler};
+
+/// A periodic job of a [`Schedule`].
+struct Job {
+    period: TickType_t,
+    /// Tick count at which the job runs next.
+    next_due: TickType_t,
+    callback: Box<dyn FnMut() + Send>,
+}
+
+impl Job {
+    /// Returns `true` if the job is due at `now`, handling the tick count wrapping around.
+    fn is_due(&self, now: TickType_t) -> bool {
+        now.wrapping_sub(self.next_due) <= TickType_t::MAX / 2
+    }
+}
+
+/// Called with the error if the timer could not be re-armed for the next due job, see
+/// [`ScheduleBuilder::on_rearm_failure`].
+type RearmFailureCallback = Box<dyn FnMut(FreeRtosError) + Send>;
+
+/// Builder for a [`Schedule`], see [`Schedule::new`].
+pub struct ScheduleBuilder {
+    name: Option<&'static CStr>,
+    jobs: Vec<Job>,
+    on_rearm_failure: Option<RearmFailureCallback>,
+}
+
+impl ScheduleBuilder {
+    /// Sets the name of the FreeRTOS timer.
+    pub fn name(mut self, name: &'static CStr) -> Self {
+        self.name = Some(name);
+        self
+    }
+
+    /// Adds a job calling `callback` every `period`, starting one `period` after [`start`](Self::start).
+    pub fn every(mut self, period: Duration, callback: impl FnMut() + Send + 'static) -> Self {
+        self.jobs.push(Job {
+            period: period.ticks(),
+            next_due: 0,
+            callback: Box::new(callback),
+        });
+        self
+    }
+
+    /// Sets a callback invoked on the timer service task if the timer could not be re-armed for the next due job.
+    ///
+    /// This happens if the timer command queue is full. The jobs keep running, the timer fires again after its previous
+    /// period and retries, so jobs may run late until the re-arm succeeds.
+    pub fn on_rearm_failure(
+        mut self,
+        callback: impl FnMut(FreeRtosError) + Send + 'static,
+    ) -> Self {
+        self.on_rearm_failure = Some(Box::new(callback));
+        self
+    }
+
+    /// Creates and starts the timer running the jobs.
+    ///
+    /// Fails with [`FreeRtosError::ZeroDuration`] if no job was added or a job has a zero period.
+    pub fn start(self) -> Result<Schedule, FreeRtosError> {
+        let Self {
+            name,
+            mut jobs,
+            mut on_rearm_failure,
+        } = self;
+
+        let first_period = jobs.iter().map(|job| job.period).min().unwrap_or_default();
+        if first_period == 0 || jobs.iter().any(|job| job.period == 0) {
+            return Err(FreeRtosError::ZeroDuration);
+        }
+
+        let start = scheduler::get_tick_count();
+        for job in &mut jobs {
+            job.next_due = start.wrapping_add(job.period);
+        }
+
+        let callback: BoxedTimerCallback = Box::new(move |handle| {
+            if let Err(error) = run_due_jobs(&mut jobs, handle)
+                && let Some(on_rearm_failure) = &mut on_rearm_failure
+            {
+                on_rearm_failure(error);
+            }
+        });
+        // Auto-reload keeps the jobs running if re-arming fails, the timer then fires again after its previous period.
+        let timer = Timer::periodic(name, Duration::from_ticks(first_period), callback)?;
+        timer.handle().start()?;
+
+        Ok(Schedule { timer })
+    }
+}
+
+impl Debug for ScheduleBuilder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ScheduleBuilder")
+            .field("name", &self.name)
+            .field("jobs", &self.jobs.len())
+            .field("on_rearm_failure", &self.on_rearm_failure.is_some())
+            .finish()
+    }
+}
+
+/// Runs all due jobs and changes the timer period to expire at the next due job.
+///
+/// Runs on the timer service task. Periods missed because the timer service task was delayed are skipped. Returns the
+/// error if the period could not be changed, the timer keeps its previous period in that case.
+fn run_due_jobs(jobs: &mut [Job], handle: TimerHandle) -> Result<(), FreeRtosError> {
+    let now = scheduler::get_tick_count();
+
+    for job in jobs.iter_mut().filter(|job| job.is_due(now)) {
+        (job.callback)();
+
+        while job.is_due(now) {
+            job.next_due = job.next_due.wrapping_add(job.period);
+        }
+    }
+
+    let until_next = jobs
+        .iter()
+        .map(|job| job.next_due.wrapping_sub(now))
+        .min()
+        .expect("a schedule has at least one job");
+
+    handle.change_period_from_callback(Duration::from_ticks(until_next))
+}
+
+/// Periodic jobs with individual periods, all driven by a single auto-reloading FreeRTOS [`Timer`].
+///
+/// Compared to one [`Timer::periodic`] per job this needs a single timer, its period is changed after each expiry so
+/// that it expires when the next job is due. The jobs run on the timer service task, like timer callbacks. Dropping
+/// the schedule deletes the timer and the jobs.
+///
+/// ```ignore
+/// let schedule = Schedule::new()
+///     .every(Duration::from_ms(10), || poll_sensors())
+///     .every(Duration::from_ms(250), || blink_led())
+///     .start()?;
+/// ```
+pub struct Schedule {
+    timer: Timer<BoxedTimerCallback>,
+}
+
+impl Schedule {
+    /// Creates a builder for a schedule without jobs.
+    #[allow(clippy::new_ret_no_self)]
+    pub fn new() -> ScheduleBuilder {
+        ScheduleBuilder {
+            name: None,
+            jobs: Vec::new(),
+            on_rearm_failure: None,
+        }
+    }
+
+    /// Returns the handle of the timer driving the jobs.
+    pub fn handle(&self) -> TimerHandle {
+        self.timer.handle()
+    }
+
+    /// Detaches the timer from Rust's memory management, the jobs keep running forever.
+    pub fn detach(self) {
+        self.timer.detach();
+    }
+}
+
+impl Debug for Schedule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Schedule")
+            .field("handle", &self.timer.handle())
+            .finish()
+    }
+}
@@ -1,7 +1,25 @@
-use veecle_freertos_sys::bindings::{TickType_t, vTaskStartScheduler, xTaskGetTickCount};
+use veecle_freertos_sys::bindings::{
+    TickType_t, shim_taskENTER_CRITICAL, shim_taskEXIT_CRITICAL, taskSCHEDULER_NOT_STARTED,
+    taskSCHEDULER_RUNNING, vTaskStartScheduler, vTaskSuspendAll, xTaskGetSchedulerState,
+    xTaskGetTickCount, xTaskGetTickCountFromISR, xTaskResumeAll,
+};
+
+use core::sync::atomic::AtomicU32;
+use core::sync::atomic::Ordering::Relaxed;
 
 use crate::Duration;
 
+#[cfg(feature = "posix-test")]
+mod host;
+
+#[cfg(feature = "posix-test")]
+pub use self::host::{
+    HostScheduler, TICK_RATE_TOLERANCE_PERCENT, effective_tick_rate, spawn_on_host_thread,
+};
+
+/// Nesting depth of critical sections entered via [`with_critical_section`].
+static CRITICAL_NESTING_DEPTH: AtomicU32 = AtomicU32::new(0);
+
 /// Starts the FreeRTOS scheduler.
 ///
 /// This function isn't expected to return unless `vTaskEndScheduler` is called.
@@ -13,6 +31,61 @@ pub fn start_scheduler() {
     }
 }
 
+/// State of the FreeRTOS scheduler, see [`state`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SchedulerState {
+    /// [`start_scheduler`] has not been called yet.
+    NotStarted,
+    /// The scheduler is running.
+    Running,
+    /// The scheduler is suspended via `vTaskSuspendAll`.
+    Suspended,
+}
+
+/// Returns the current state of the scheduler.
+///
+/// Code that may run before the scheduler starts can use this to avoid blocking FreeRTOS calls.
+pub fn state() -> SchedulerState {
+    // SAFETY:
+    // No requirements on the caller, this may be called before the scheduler is started.
+    let state = unsafe { xTaskGetSchedulerState() };
+
+    if state == taskSCHEDULER_NOT_STARTED() {
+        SchedulerState::NotStarted
+    } else if state == taskSCHEDULER_RUNNING() {
+        SchedulerState::Running
+    } else {
+        SchedulerState::Suspended
+    }
+}
+
+/// Panics in debug builds if a FreeRTOS API would block for `wait` while the scheduler is suspended.
+///
+/// FreeRTOS does not support blocking with the scheduler suspended, this turns the misuse into an actionable error
+/// during development. Non-blocking calls, with a zero `wait`, are allowed.
+#[track_caller]
+pub(crate) fn debug_assert_may_block(wait: Duration) {
+    if cfg!(debug_assertions) && wait.ticks() != 0 {
+        assert!(
+            state() != SchedulerState::Suspended,
+            "blocking FreeRTOS API called while the scheduler is suspended"
+        );
+    }
+}
+
+/// Returns `true` if the scheduler preempts tasks, as set by `configUSE_PREEMPTION` at compile time.
+///
+/// Without preemption, a task only gives up the CPU when it blocks or yields explicitly.
+pub const fn preemption_enabled() -> bool {
+    cfg!(configUSE_PREEMPTION)
+}
+
+/// Returns `true` if the scheduler shares CPU time between ready tasks of equal priority on every tick, as set by
+/// `configUSE_TIME_SLICING` at compile time.
+pub const fn time_slicing_enabled() -> bool {
+    cfg!(configUSE_TIME_SLICING)
+}
+
 /// Returns the count of ticks since [start_scheduler] was called.
 pub fn get_tick_count() -> TickType_t {
     // SAFETY:
@@ -21,7 +94,74 @@ pub fn get_tick_count() -> TickType_t {
     unsafe { xTaskGetTickCount() }
 }
 
+/// Like [get_tick_count], but safe to call from ISR contexts.
+pub fn get_tick_count_from_isr() -> TickType_t {
+    // SAFETY:
+    // No requirements on the caller, this function is safe to call from ISR and non-ISR contexts.
+    unsafe { xTaskGetTickCountFromISR() }
+}
+
 /// Like [get_tick_count], but returns the time since [start_scheduler] was called as a [Duration].
 pub fn get_tick_count_duration() -> Duration {
     Duration::from_ticks(get_tick_count())
 }
+
+/// Runs `f` with the scheduler suspended, preventing context switches while it runs.
+///
+/// Interrupts stay enabled. `f` must not call FreeRTOS API functions that may block.
+pub(crate) fn with_scheduler_suspended<R>(f: impl FnOnce() -> R) -> R {
+    // SAFETY:
+    // No requirements on the caller in non-ISR contexts. Calls may be nested, every call is paired with the
+    // `xTaskResumeAll` below.
+    unsafe {
+        vTaskSuspendAll();
+    }
+
+    let result = f();
+
+    // SAFETY:
+    // The scheduler was suspended by the matching `vTaskSuspendAll` above.
+    unsafe {
+        xTaskResumeAll();
+    }
+
+    result
+}
+
+/// Runs `f` inside a critical section and returns its result.
+///
+/// Interrupts (up to the port's maximum syscall interrupt priority) and context switches are disabled while `f` runs,
+/// so `f` should be short and must not call FreeRTOS API functions that may block. Critical sections may be nested.
+///
+/// The critical section is exited once `f` returns, including early returns within `f`. It is not exited if `f`
+/// panics.
+pub fn with_critical_section<R>(f: impl FnOnce() -> R) -> R {
+    // SAFETY:
+    // No requirements on the caller in non-ISR contexts. Every call is paired with the `taskEXIT_CRITICAL` below.
+    unsafe {
+        shim_taskENTER_CRITICAL();
+    }
+    CRITICAL_NESTING_DEPTH.fetch_add(1, Relaxed);
+
+    let result = f();
+
+    CRITICAL_NESTING_DEPTH.fetch_sub(1, Relaxed);
+    // SAFETY:
+    // The critical section was entered by the matching `taskENTER_CRITICAL` above.
+    unsafe {
+        shim_taskEXIT_CRITICAL();
+    }
+
+    result
+}
+
+/// Returns how deeply nested the currently executing code is in critical sections entered via
+/// [`with_critical_section`].
+///
+/// Intended for debugging, e.g. asserting that the depth is zero before blocking. The nesting counters of FreeRTOS
+/// ports are private to the port, so critical sections entered from C code or directly via `taskENTER_CRITICAL` are
+/// not counted. The count is global, which is only accurate on single-core ports, where code in a critical section
+/// can't be interrupted by other tasks.
+pub fn critical_nesting_depth() -> u32 {
+    CRITICAL_NESTING_DEPTH.load(Relaxed)
+}
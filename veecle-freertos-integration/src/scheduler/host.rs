@@ -0,0 +1,146 @@
+use alloc::boxed::Box;
+use std::sync::mpsc::{Receiver, Sender, TryRecvError, channel, sync_channel};
+use std::thread::JoinHandle;
+
+use crate::{CurrentTask, Duration, Task, tick_rate_hz};
+
+/// A job or command sent from the host thread to the runner task.
+enum Message {
+    Run(Box<dyn FnOnce() + Send>),
+    Shutdown,
+}
+
+impl core::fmt::Debug for Message {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Run(_) => f.write_str("Run(..)"),
+            Self::Shutdown => f.write_str("Shutdown"),
+        }
+    }
+}
+
+/// Handle to a scheduler started by [`spawn_on_host_thread`].
+///
+/// Dropping the handle shuts the scheduler down, like [`shutdown`](Self::shutdown).
+#[derive(Debug)]
+pub struct HostScheduler {
+    messages: Sender<Message>,
+    thread: Option<JoinHandle<()>>,
+}
+
+/// Starts the FreeRTOS scheduler on a dedicated OS thread, for tests on the POSIX port.
+///
+/// The host thread interacts with FreeRTOS through [`HostScheduler::run`], which executes closures inside a FreeRTOS
+/// task. The host thread itself must never call FreeRTOS APIs. Closures and their results are passed through
+/// `std` channels, so the global allocator must be safe to use from both the host thread and FreeRTOS tasks: use the
+/// system allocator, not [`FreeRtosAllocator`](crate::FreeRtosAllocator).
+///
+/// FreeRTOS state is not reset when the scheduler ends, so this should be called at most once per process.
+pub fn spawn_on_host_thread() -> HostScheduler {
+    let (messages, receiver) = channel();
+
+    let thread = std::thread::spawn(move || {
+        Task::new()
+            .name(c"host_runner")
+            .start(move |_| run_messages(receiver))
+            .expect("runner task to be created");
+
+        super::start_scheduler();
+    });
+
+    HostScheduler {
+        messages,
+        thread: Some(thread),
+    }
+}
+
+/// Tolerated deviation of the measured tick rate from `configTICK_RATE_HZ` in percent, see [`effective_tick_rate`].
+pub const TICK_RATE_TOLERANCE_PERCENT: u32 = 10;
+
+/// Measures the tick rate against wall-clock time, blocking the current task for 100 ms.
+///
+/// The timing of the POSIX port drifts under host load. If the measured rate deviates from `configTICK_RATE_HZ` by more
+/// than [`TICK_RATE_TOLERANCE_PERCENT`], the hook set via
+/// [`set_on_tick_rate_mismatch`](crate::hooks::set_on_tick_rate_mismatch) is called with the measured and configured
+/// rate.
+///
+/// Must be called from a FreeRTOS task.
+pub fn effective_tick_rate() -> u32 {
+    let start_tick = super::get_tick_count();
+    let start = std::time::Instant::now();
+
+    CurrentTask::delay(Duration::from_ms(100));
+
+    let elapsed = start.elapsed();
+    let ticks = u128::from(super::get_tick_count().wrapping_sub(start_tick));
+    let measured =
+        u32::try_from(ticks * 1_000_000 / elapsed.as_micros().max(1)).unwrap_or(u32::MAX);
+
+    let configured = tick_rate_hz();
+    if measured.abs_diff(configured) > configured / 100 * TICK_RATE_TOLERANCE_PERCENT {
+        crate::hooks::tick_rate_mismatch(measured, configured);
+    }
+
+    measured
+}
+
+/// Runs jobs sent by the host thread until asked to shut down, then ends the scheduler.
+fn run_messages(receiver: Receiver<Message>) {
+    loop {
+        // Blocking on the channel would block the FreeRTOS thread without the scheduler knowing, poll instead.
+        match receiver.try_recv() {
+            Ok(Message::Run(job)) => job(),
+            Ok(Message::Shutdown) | Err(TryRecvError::Disconnected) => break,
+            Err(TryRecvError::Empty) => CurrentTask::delay(Duration::eps()),
+        }
+    }
+
+    // SAFETY: This feature is only meant for the POSIX port, where `vTaskEndScheduler` does not have any requirements
+    // on the caller.
+    unsafe { veecle_freertos_sys::bindings::vTaskEndScheduler() };
+}
+
+impl HostScheduler {
+    /// Runs `f` inside a FreeRTOS task and blocks the host thread until it returns its result.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the scheduler was shut down.
+    ///
+    /// A panic in `f` is not propagated to the host thread, it reaches the `extern "C"` task entry and aborts the
+    /// program.
+    pub fn run<R>(&self, f: impl FnOnce() -> R + Send + 'static) -> R
+    where
+        R: Send + 'static,
+    {
+        let (result_sender, result) = sync_channel(1);
+
+        self.messages
+            .send(Message::Run(Box::new(move || {
+                // The host thread may have given up waiting, the result is dropped in that case.
+                let _ = result_sender.send(f());
+            })))
+            .expect("scheduler to be running");
+
+        result.recv().expect("job to complete")
+    }
+
+    /// Ends the scheduler and waits for its thread to exit.
+    pub fn shutdown(mut self) {
+        self.shutdown_inner();
+    }
+
+    fn shutdown_inner(&mut self) {
+        if let Some(thread) = self.thread.take() {
+            // The runner task also shuts down if the channel is disconnected, ignore a send failure.
+            let _ = self.messages.send(Message::Shutdown);
+            thread.join().expect("scheduler thread not to panic");
+        }
+    }
+}
+
+impl Drop for HostScheduler {
+    fn drop(&mut self) {
+        self.shutdown_inner();
+    }
+}
@@ -0,0 +1,110 @@
+//! Asynchronous counting semaphore.
+
+use alloc::sync::Arc;
+use core::future::poll_fn;
+use core::ptr::null;
+use core::task::Poll;
+
+use veecle_freertos_sys::bindings::{
+    QueueHandle_t, UBaseType_t, pdTRUE, shim_xQueueSendToBack, uxQueueMessagesWaiting,
+    vQueueDelete, xQueueCreateCountingSemaphore, xQueueSemaphoreTake,
+};
+
+use crate::FreeRtosError;
+use crate::waker_set::WakerSet;
+
+/// Shared state of an [`AsyncSemaphore`].
+#[derive(Debug)]
+struct SemaphoreInner {
+    /// Always a valid undeleted counting semaphore handle.
+    handle: QueueHandle_t,
+    /// Wakers of all pending acquirers, there may be many as [`AsyncSemaphore`] is [`Clone`].
+    acquire_waker: WakerSet,
+}
+
+// SAFETY: The struct only contains a pointer to the FreeRTOS resource and a `WakerSet`, which is `Send`.
+unsafe impl Send for SemaphoreInner {}
+
+// SAFETY: The struct only contains a pointer to the FreeRTOS resource and a `WakerSet`, which is `Sync`.
+unsafe impl Sync for SemaphoreInner {}
+
+impl Drop for SemaphoreInner {
+    fn drop(&mut self) {
+        // SAFETY:
+        // The semaphore is created during the construction of Self and only deleted here, ensuring the handle is valid.
+        unsafe { vQueueDelete(self.handle) }
+    }
+}
+
+/// A counting semaphore whose [`acquire`](Self::acquire) waits asynchronously instead of blocking the task.
+///
+/// Useful to limit the number of concurrent operations in async code. Clones share the same semaphore.
+#[derive(Debug, Clone)]
+pub struct AsyncSemaphore(Arc<SemaphoreInner>);
+
+impl AsyncSemaphore {
+    /// Creates a new semaphore with `initial_count` of `max_count` permits available.
+    pub fn new(max_count: UBaseType_t, initial_count: UBaseType_t) -> Result<Self, FreeRtosError> {
+        // SAFETY:
+        // `xQueueCreateCountingSemaphore` has no requirements on its arguments, it returns null if they are invalid.
+        let handle = unsafe { xQueueCreateCountingSemaphore(max_count, initial_count) };
+
+        if handle.is_null() {
+            return Err(FreeRtosError::OutOfMemory);
+        }
+
+        Ok(Self(Arc::new(SemaphoreInner {
+            handle,
+            acquire_waker: WakerSet::new(),
+        })))
+    }
+
+    /// Returns the number of available permits.
+    pub fn available(&self) -> UBaseType_t {
+        // SAFETY:
+        // Our handle is always a valid undeleted semaphore handle.
+        unsafe { uxQueueMessagesWaiting(self.0.handle) }
+    }
+
+    /// Takes a permit without waiting, returns `false` if none is available.
+    pub fn try_acquire(&self) -> bool {
+        // SAFETY:
+        // Our handle is always a valid undeleted semaphore handle.
+        unsafe { xQueueSemaphoreTake(self.0.handle, 0) == pdTRUE() }
+    }
+
+    /// Takes a permit, staying pending until one is available.
+    pub async fn acquire(&self) {
+        poll_fn(|cx| {
+            if self.try_acquire() {
+                return Poll::Ready(());
+            }
+
+            // Try again after registering the waker, so that a permit released in between isn't missed.
+            self.0.acquire_waker.register(cx.waker());
+
+            if self.try_acquire() {
+                Poll::Ready(())
+            } else {
+                Poll::Pending
+            }
+        })
+        .await
+    }
+
+    /// Returns a permit and wakes the pending acquirers.
+    ///
+    /// Returns [`FreeRtosError::QueueFull`] if all `max_count` permits are already available.
+    pub fn release(&self) -> Result<(), FreeRtosError> {
+        // SAFETY:
+        // Our handle is always a valid undeleted semaphore handle. Semaphores have an item size of zero, so no data is
+        // read from the null item pointer.
+        if unsafe { shim_xQueueSendToBack(self.0.handle, null(), 0) } != pdTRUE() {
+            return Err(FreeRtosError::QueueFull);
+        }
+
+        // All acquirers are woken, those that lose the race for the permit register again.
+        self.0.acquire_waker.wake();
+        Ok(())
+    }
+}
@@ -1,6 +1,6 @@
 use core::future::Future;
 use core::pin::pin;
-use core::task::{Context, Poll};
+use core::task::{Context, Poll, Waker};
 
 use crate::{CurrentTask, Duration, Task};
 
@@ -48,6 +48,16 @@ mod waker {
     }
 }
 
+/// Creates a [`Waker`] that wakes `task` by incrementing its notification value, see [`TaskNotification::Increment`].
+///
+/// Useful to build custom executors or to integrate other async sources, where the woken task waits with
+/// [`CurrentTask::take_notification`]. This is the waker used by [`block_on_future`].
+///
+/// [`TaskNotification::Increment`]: crate::TaskNotification::Increment
+pub fn waker_for(task: &Task) -> Waker {
+    waker::new(task.clone())
+}
+
 /// Runs a future to completion on the current task and returns its output value.
 ///
 /// # Panics
@@ -0,0 +1,90 @@
+use alloc::sync::Arc;
+use core::cell::UnsafeCell;
+use core::ptr;
+use core::sync::atomic::Ordering::SeqCst;
+use core::sync::atomic::{AtomicBool, AtomicPtr};
+
+use crate::{CurrentTask, Duration, Task, TaskNotification};
+
+/// Result slot shared between a task started with
+/// [`TaskBuilder::start_with_result`](super::TaskBuilder::start_with_result) and its [`JoinHandle`].
+#[derive(Debug)]
+pub(super) struct JoinState<T> {
+    value: UnsafeCell<Option<T>>,
+    finished: AtomicBool,
+    /// Handle of the task waiting in [`JoinHandle::join`], or null.
+    waiter: AtomicPtr<()>,
+}
+
+// SAFETY: `value` is written once by the started task before `finished` is set and only read by the single
+// `JoinHandle` after observing `finished`, so the accesses never overlap. `T` is moved between tasks, so it must be
+// `Send`.
+unsafe impl<T: Send> Sync for JoinState<T> {}
+
+impl<T> JoinState<T> {
+    pub(super) fn new() -> Self {
+        Self {
+            value: UnsafeCell::new(None),
+            finished: AtomicBool::new(false),
+            waiter: AtomicPtr::new(ptr::null_mut()),
+        }
+    }
+
+    /// Stores the task's result and wakes the joining task, if any. Must be called at most once.
+    pub(super) fn finish(&self, value: T) {
+        // SAFETY: This is called once by the started task before `finished` is set, the `JoinHandle` doesn't access
+        // `value` before observing `finished`.
+        unsafe { *self.value.get() = Some(value) };
+        self.finished.store(true, SeqCst);
+
+        let waiter = self.waiter.load(SeqCst);
+        if !waiter.is_null() {
+            // SAFETY: The waiter stored its own task handle, tasks are never deleted.
+            unsafe { Task::from_raw_handle(waiter.cast()) }.notify(TaskNotification::Increment);
+        }
+    }
+}
+
+/// Handle to retrieve the value computed by a task started with
+/// [`TaskBuilder::start_with_result`](super::TaskBuilder::start_with_result).
+///
+/// Tasks can't return in FreeRTOS, the task is finished once its closure returned, afterwards it stays suspended
+/// forever.
+#[derive(Debug)]
+pub struct JoinHandle<T> {
+    pub(super) task: Task,
+    pub(super) state: Arc<JoinState<T>>,
+}
+
+impl<T> JoinHandle<T> {
+    /// Returns the started task.
+    pub fn task(&self) -> &Task {
+        &self.task
+    }
+
+    /// Returns `true` if the task's closure returned.
+    pub fn is_finished(&self) -> bool {
+        self.state.finished.load(SeqCst)
+    }
+
+    /// Blocks the current task until the started task finished and returns its value.
+    ///
+    /// The current task is woken via its task notification, see [`Task::notify`], other notifications received while
+    /// waiting are consumed.
+    ///
+    /// # Panics
+    ///
+    /// If run from outside a [`Task`].
+    pub fn join(self) -> T {
+        let current = Task::current().expect("joining a task is only possible from within a task");
+        self.state.waiter.store(current.raw_handle().cast(), SeqCst);
+
+        while !self.is_finished() {
+            CurrentTask::take_notification(true, Duration::infinite());
+        }
+
+        // SAFETY: `finished` is only set after `value` was written and the started task never accesses it again. This
+        // is the only `JoinHandle` for the state.
+        unsafe { (*self.state.value.get()).take() }.expect("finished task to have stored its value")
+    }
+}
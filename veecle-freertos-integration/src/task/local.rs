@@ -0,0 +1,81 @@
+use alloc::boxed::Box;
+use core::ffi::c_void;
+use core::marker::PhantomData;
+
+use veecle_freertos_sys::bindings::configNUM_THREAD_LOCAL_STORAGE_POINTERS;
+
+use crate::Task;
+
+/// A value stored per task in one of the task's thread local storage pointers.
+///
+/// Each task sees its own value. Values are boxed and leaked, they live as long as the task they belong to, which is
+/// forever as tasks can't be deleted.
+///
+/// ```ignore
+/// // SAFETY: Index 0 isn't used by anything else.
+/// static REQUEST_ID: TaskLocal<u32> = unsafe { TaskLocal::new(0) };
+///
+/// let id = REQUEST_ID.get_or_init(|| 42);
+/// ```
+#[derive(Debug)]
+pub struct TaskLocal<T> {
+    index: usize,
+    value_type: PhantomData<fn() -> T>,
+}
+
+impl<T> TaskLocal<T>
+where
+    T: Sync + 'static,
+{
+    /// Creates a `TaskLocal` using the thread local storage pointer at `index` of every task.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is not below `configNUM_THREAD_LOCAL_STORAGE_POINTERS`.
+    ///
+    /// # Safety
+    ///
+    /// The pointer at `index` must not be used by anything but this `TaskLocal`, neither through
+    /// [`Task::set_local_storage`], another `TaskLocal` nor C code.
+    pub const unsafe fn new(index: usize) -> Self {
+        assert!(
+            index < configNUM_THREAD_LOCAL_STORAGE_POINTERS as usize,
+            "thread local storage index out of range"
+        );
+
+        Self {
+            index,
+            value_type: PhantomData,
+        }
+    }
+
+    /// Returns the current task's value, if one was set.
+    pub fn get(&self) -> Option<&'static T> {
+        let task = Task::current().expect("task locals can only be used from within a task");
+        let value = task.get_local_storage(self.index).cast::<T>();
+
+        // SAFETY:
+        // The pointer at `index` is exclusively used by this `TaskLocal`, so it is either null or points to a leaked
+        // `Box<T>` of the current task that is never freed.
+        unsafe { value.as_ref() }
+    }
+
+    /// Returns the current task's value, initializing it with `init` if none was set.
+    pub fn get_or_init(&self, init: impl FnOnce() -> T) -> &'static T {
+        if let Some(value) = self.get() {
+            return value;
+        }
+
+        let task = Task::current().expect("task locals can only be used from within a task");
+        let value = Box::leak(Box::new(init()));
+
+        // SAFETY:
+        // The pointer at `index` is exclusively used by this `TaskLocal`, and only the current task accesses its own
+        // pointer, so no other value can have been set since checking above.
+        unsafe {
+            task.set_local_storage(self.index, (&raw mut *value).cast::<c_void>());
+        }
+
+        value
+    }
+}
@@ -0,0 +1,82 @@
+use core::cell::{Cell, UnsafeCell};
+use core::ops::{Deref, DerefMut};
+
+/// A cell sharing mutable state between futures driven by the same task, e.g. by one
+/// [`block_on_future`](super::block_on_future) call.
+///
+/// Such futures never run concurrently, they only interleave at await points, so no real mutex is needed. The
+/// exclusive borrow is tracked at runtime instead: borrowing while another borrow is alive, e.g. one held across an
+/// await point by another future, panics in [`borrow_mut`](Self::borrow_mut) and fails in
+/// [`try_borrow_mut`](Self::try_borrow_mut).
+///
+/// `LocalCell` is not `Sync`, so it can't be shared between tasks.
+#[derive(Debug)]
+pub struct LocalCell<T> {
+    borrowed: Cell<bool>,
+    value: UnsafeCell<T>,
+}
+
+impl<T> LocalCell<T> {
+    /// Creates a new `LocalCell` containing `value`.
+    pub const fn new(value: T) -> Self {
+        Self {
+            borrowed: Cell::new(false),
+            value: UnsafeCell::new(value),
+        }
+    }
+
+    /// Mutably borrows the contained value until the returned guard is dropped.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is currently borrowed.
+    pub fn borrow_mut(&self) -> LocalCellGuard<'_, T> {
+        self.try_borrow_mut()
+            .expect("LocalCell already borrowed, a borrow must not be held while another future uses the cell")
+    }
+
+    /// Mutably borrows the contained value until the returned guard is dropped, or returns `None` if the value is
+    /// currently borrowed.
+    pub fn try_borrow_mut(&self) -> Option<LocalCellGuard<'_, T>> {
+        if self.borrowed.replace(true) {
+            return None;
+        }
+
+        Some(LocalCellGuard { cell: self })
+    }
+
+    /// Consumes the cell, returning the contained value.
+    pub fn into_inner(self) -> T {
+        self.value.into_inner()
+    }
+}
+
+/// An exclusive borrow of the value in a [`LocalCell`], see [`LocalCell::borrow_mut`].
+#[derive(Debug)]
+pub struct LocalCellGuard<'a, T> {
+    cell: &'a LocalCell<T>,
+}
+
+impl<T> Deref for LocalCellGuard<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        // SAFETY:
+        // The guard only exists while `borrowed` is set, so there is no other reference to the value.
+        unsafe { &*self.cell.value.get() }
+    }
+}
+
+impl<T> DerefMut for LocalCellGuard<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        // SAFETY:
+        // The guard only exists while `borrowed` is set, so there is no other reference to the value.
+        unsafe { &mut *self.cell.value.get() }
+    }
+}
+
+impl<T> Drop for LocalCellGuard<'_, T> {
+    fn drop(&mut self) {
+        self.cell.borrowed.set(false);
+    }
+}
@@ -3,23 +3,73 @@
 use alloc::boxed::Box;
 use alloc::ffi::CString;
 use alloc::string::String;
+use alloc::sync::Arc;
 use core::ffi::CStr;
 use core::ptr::null_mut;
 
 use veecle_freertos_sys::bindings::{
-    StackType_t, TaskHandle_t, UBaseType_t, eNotifyAction, eNotifyAction_eIncrement,
+    StackType_t, TaskHandle_t, UBaseType_t, configMAX_PRIORITIES,
+    configTASK_NOTIFICATION_ARRAY_ENTRIES, eNotifyAction, eNotifyAction_eIncrement,
     eNotifyAction_eNoAction, eNotifyAction_eSetBits, eNotifyAction_eSetValueWithOverwrite,
     eNotifyAction_eSetValueWithoutOverwrite, pdFALSE, pdTRUE, shim_pcTaskGetName,
     shim_ulTaskNotifyTake, shim_xTaskNotify, shim_xTaskNotifyFromISR, shim_xTaskNotifyWait,
-    uxTaskGetStackHighWaterMark, uxTaskGetTaskNumber, vTaskDelay, vTaskSetTaskNumber, vTaskSuspend,
-    xTaskCreate, xTaskGetCurrentTaskHandle,
+    taskYIELD, tskIDLE_PRIORITY, uxTaskGetNumberOfTasks, uxTaskGetStackHighWaterMark,
+    uxTaskGetTaskNumber, uxTaskPriorityGet, vTaskDelay, vTaskPrioritySet, vTaskResume,
+    vTaskSetTaskNumber, vTaskSuspend, xTaskCreate, xTaskGenericNotify, xTaskGenericNotifyFromISR,
+    xTaskGenericNotifyWait, xTaskGetCurrentTaskHandle,
 };
 
-pub use self::block_on_future::block_on_future;
+pub use self::block_on_future::{block_on_future, waker_for};
+pub use self::join_handle::JoinHandle;
+#[cfg(configNUM_THREAD_LOCAL_STORAGE_POINTERS)]
+pub use self::local::TaskLocal;
+pub use self::local_cell::{LocalCell, LocalCellGuard};
+pub use self::notify_group::NotifyGroup;
+pub use self::oneshot::{NotifyReceiver, NotifySender, oneshot};
+#[cfg(feature = "std")]
+pub use self::panic_monitor::PanicMonitor;
+#[cfg(all(
+    any(
+        configUSE_STATS_FORMATTING_FUNCTIONS = "1",
+        configUSE_STATS_FORMATTING_FUNCTIONS = "2"
+    ),
+    configUSE_TRACE_FACILITY
+))]
+pub use self::stats_formatting::list;
+#[cfg(all(
+    any(
+        configUSE_STATS_FORMATTING_FUNCTIONS = "1",
+        configUSE_STATS_FORMATTING_FUNCTIONS = "2"
+    ),
+    configUSE_TRACE_FACILITY,
+    configGENERATE_RUN_TIME_STATS
+))]
+pub use self::stats_formatting::runtime_stats;
+#[cfg(configUSE_TRACE_FACILITY)]
+pub use self::system_state::{TaskInfo, TaskState, TaskStatus, system_state};
+pub use self::timeout::{Timeout, with_timeout};
+pub use self::worker_pool::{Job, WorkerPool};
 use crate::units::Duration;
-use crate::{FreeRtosError, InterruptContext};
+use crate::{FreeRtosError, InterruptContext, scheduler};
 
 mod block_on_future;
+mod join_handle;
+#[cfg(configNUM_THREAD_LOCAL_STORAGE_POINTERS)]
+mod local;
+mod local_cell;
+mod notify_group;
+mod oneshot;
+#[cfg(feature = "std")]
+mod panic_monitor;
+#[cfg(any(
+    configUSE_STATS_FORMATTING_FUNCTIONS = "1",
+    configUSE_STATS_FORMATTING_FUNCTIONS = "2"
+))]
+mod stats_formatting;
+#[cfg(configUSE_TRACE_FACILITY)]
+mod system_state;
+mod timeout;
+mod worker_pool;
 
 // SAFETY: All task APIs we expose are fine to call from any task/thread because they use internal locking where
 // necessary, or they are marked unsafe and it's up to users to provide thread safety on those specific APIs.
@@ -44,6 +94,17 @@ pub struct Task {
 #[derive(Debug, Copy, Clone)]
 pub struct TaskPriority(pub UBaseType_t);
 
+/// Set of cores a task is allowed to run on, bit `n` set means the task may run on core `n`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct CoreAffinityMask(pub UBaseType_t);
+
+impl CoreAffinityMask {
+    /// Returns `true` if the mask allows running on `core`.
+    pub fn contains(self, core: u32) -> bool {
+        core < UBaseType_t::BITS && self.0 & (1 << core) != 0
+    }
+}
+
 /// Notification to be sent to a task.
 #[derive(Debug, Copy, Clone)]
 pub enum TaskNotification {
@@ -74,6 +135,16 @@ impl TaskNotification {
 }
 
 impl TaskPriority {
+    /// Returns the priority of the idle task, the lowest priority.
+    pub fn idle() -> Self {
+        TaskPriority(tskIDLE_PRIORITY())
+    }
+
+    /// Returns the highest priority a task can have, `configMAX_PRIORITIES - 1`.
+    pub fn max() -> Self {
+        TaskPriority(configMAX_PRIORITIES as UBaseType_t - 1)
+    }
+
     fn to_freertos(self) -> UBaseType_t {
         self.0
     }
@@ -88,6 +159,9 @@ pub struct TaskBuilder {
     task_name: CString,
     task_stack_size: StackType_t,
     task_priority: TaskPriority,
+    task_core_affinity: Option<CoreAffinityMask>,
+    #[cfg(feature = "std")]
+    task_catch_panics: bool,
 }
 
 impl TaskBuilder {
@@ -109,19 +183,180 @@ impl TaskBuilder {
         self
     }
 
+    /// Set the cores the task is allowed to run on.
+    ///
+    /// Only has an effect on SMP configurations with `configUSE_CORE_AFFINITY` enabled, the task is created pinned to
+    /// the given cores. Otherwise the mask is ignored.
+    pub fn core_affinity(&mut self, core_affinity: CoreAffinityMask) -> &mut Self {
+        self.task_core_affinity = Some(core_affinity);
+        self
+    }
+
+    /// Catch panics of the task instead of aborting the program.
+    ///
+    /// A panic is passed to the hook set via [`hooks::set_on_task_panic`](crate::hooks::set_on_task_panic), then the
+    /// task is suspended forever, e.g. to let a supervisor log the failure or start a replacement task. Requires
+    /// building with `panic = "unwind"`, otherwise panics abort the program as usual.
+    #[cfg(feature = "std")]
+    pub fn catch_panics(&mut self) -> &mut Self {
+        self.task_catch_panics = true;
+        self
+    }
+
     /// Start a new task that can't return a value.
     pub fn start<F>(&self, func: F) -> Result<Task, FreeRtosError>
     where
         F: FnOnce(Task),
         F: Send + 'static,
     {
+        #[cfg(feature = "std")]
+        if self.task_catch_panics {
+            return Task::spawn(
+                &self.task_name,
+                self.task_stack_size,
+                self.task_priority,
+                self.task_core_affinity,
+                move |task| crate::hooks::run_catching_panics(task, func),
+            );
+        }
+
         Task::spawn(
             &self.task_name,
             self.task_stack_size,
             self.task_priority,
+            self.task_core_affinity,
             func,
         )
     }
+
+    /// Start a new task that is suspended until [`Task::resume`] is called.
+    ///
+    /// This allows creating a set of tasks and releasing them together once all are ready. The task is suspended with
+    /// the scheduler suspended, so it can't run before, even if it has a higher priority than the calling task.
+    pub fn start_suspended<F>(&self, func: F) -> Result<Task, FreeRtosError>
+    where
+        F: FnOnce(Task),
+        F: Send + 'static,
+    {
+        let create_suspended = || {
+            let task = self.start(func)?;
+            // SAFETY:
+            // The handle refers to the task that was just created, which is not the calling task.
+            unsafe { vTaskSuspend(task.task_handle) };
+            Ok(task)
+        };
+
+        if scheduler::state() == scheduler::SchedulerState::NotStarted {
+            create_suspended()
+        } else {
+            scheduler::with_scheduler_suspended(create_suspended)
+        }
+    }
+
+    /// Start a new task that waits for `delay` before running `func`.
+    ///
+    /// Useful to stagger the startup of periodic tasks, so their work doesn't align. The delay starts once the task
+    /// runs for the first time, not when this is called.
+    pub fn start_delayed<F>(&self, delay: Duration, func: F) -> Result<Task, FreeRtosError>
+    where
+        F: FnOnce(Task),
+        F: Send + 'static,
+    {
+        self.start(move |task| {
+            CurrentTask::delay(delay);
+            func(task)
+        })
+    }
+
+    /// Start a new task without allocating, using the given stack and task control block.
+    ///
+    /// Unlike [`start`](Self::start), `func` can't capture state, as storing a closure would require an allocation.
+    /// Use statics to pass data to the task instead. The configured stack size is ignored in favor of the length of
+    /// `stack`.
+    ///
+    /// Only available if `configSUPPORT_STATIC_ALLOCATION` is enabled.
+    #[cfg(configSUPPORT_STATIC_ALLOCATION)]
+    pub fn start_static(
+        &self,
+        stack: &'static mut [StackType_t],
+        task_buffer: &'static mut veecle_freertos_sys::bindings::StaticTask_t,
+        func: fn(Task),
+    ) -> Result<Task, FreeRtosError> {
+        use core::ffi::c_void;
+
+        extern "C" fn thread_start(main: *mut c_void) {
+            // SAFETY:
+            // The `main` pointer is the `fn(Task)` passed as parameter into `xTaskCreateStatic` below.
+            let main = unsafe { core::mem::transmute::<*mut c_void, fn(Task)>(main) };
+
+            main(CurrentTask::handle());
+
+            panic!("Not allowed to quit the task!");
+        }
+
+        // SAFETY:
+        // The function `thread_start` cannot finish without panicking, and relies on `extern "C"` doing an
+        // abort-on-panic, so it will never return to the scheduler. The parameter is a function pointer, valid
+        // forever. `stack` and `task_buffer` are exclusively borrowed forever, so nothing else can access them while
+        // the task uses them. `name` points to a valid, null-terminated cstring and outlives the call, which copies
+        // the value pointed to.
+        let task_handle = unsafe {
+            match self.task_core_affinity {
+                #[cfg(configUSE_CORE_AFFINITY)]
+                Some(core_affinity) => veecle_freertos_sys::bindings::xTaskCreateStaticAffinitySet(
+                    Some(thread_start),
+                    self.task_name.as_ptr(),
+                    stack.len() as StackType_t,
+                    func as *mut c_void,
+                    self.task_priority.to_freertos(),
+                    stack.as_mut_ptr(),
+                    task_buffer,
+                    core_affinity.0,
+                ),
+                _ => veecle_freertos_sys::bindings::xTaskCreateStatic(
+                    Some(thread_start),
+                    self.task_name.as_ptr(),
+                    stack.len() as StackType_t,
+                    func as *mut c_void,
+                    self.task_priority.to_freertos(),
+                    stack.as_mut_ptr(),
+                    task_buffer,
+                ),
+            }
+        };
+
+        if task_handle.is_null() {
+            return Err(FreeRtosError::OutOfMemory);
+        }
+
+        Ok(Task { task_handle })
+    }
+
+    /// Start a new task computing a value, which can be retrieved through the returned [`JoinHandle`].
+    ///
+    /// Once `func` returned, the task stays suspended forever.
+    pub fn start_with_result<F, T>(&self, func: F) -> Result<JoinHandle<T>, FreeRtosError>
+    where
+        F: FnOnce(Task) -> T,
+        F: Send + 'static,
+        T: Send + 'static,
+    {
+        let state = Arc::new(join_handle::JoinState::new());
+
+        let task = self.start({
+            let state = state.clone();
+            move |task| {
+                state.finish(func(task));
+                drop(state);
+
+                loop {
+                    CurrentTask::suspend();
+                }
+            }
+        })?;
+
+        Ok(JoinHandle { task, state })
+    }
 }
 
 impl Task {
@@ -148,6 +383,9 @@ impl Task {
             task_name: c"rust_task".into(),
             task_stack_size: 1024,
             task_priority: TaskPriority(1),
+            task_core_affinity: None,
+            #[cfg(feature = "std")]
+            task_catch_panics: false,
         }
     }
 
@@ -170,6 +408,7 @@ impl Task {
         name: &CStr,
         stack_size: StackType_t,
         priority: TaskPriority,
+        core_affinity: Option<CoreAffinityMask>,
     ) -> Result<Task, FreeRtosError> {
         let f = Box::new(f);
         let param_ptr = Box::into_raw(f);
@@ -184,14 +423,26 @@ impl Task {
             // `name` points to a valid, null-terminated cstring and outlives the `xTaskCreate` call, which copies the
             // value pointed to.
             let ret = unsafe {
-                xTaskCreate(
-                    Some(thread_start),
-                    name.as_ptr(),
-                    stack_size,
-                    param_ptr.cast(),
-                    priority.to_freertos(),
-                    &mut task_handle,
-                )
+                match core_affinity {
+                    #[cfg(configUSE_CORE_AFFINITY)]
+                    Some(core_affinity) => veecle_freertos_sys::bindings::xTaskCreateAffinitySet(
+                        Some(thread_start),
+                        name.as_ptr(),
+                        stack_size,
+                        param_ptr.cast(),
+                        priority.to_freertos(),
+                        core_affinity.0,
+                        &mut task_handle,
+                    ),
+                    _ => xTaskCreate(
+                        Some(thread_start),
+                        name.as_ptr(),
+                        stack_size,
+                        param_ptr.cast(),
+                        priority.to_freertos(),
+                        &mut task_handle,
+                    ),
+                }
             };
 
             (ret == pdTRUE(), task_handle)
@@ -212,9 +463,7 @@ impl Task {
             // a `Box<dyn FnOnce(Task)>`.
             let task_main_function = unsafe { Box::from_raw(main.cast::<Box<dyn FnOnce(Task)>>()) };
 
-            task_main_function(
-                Task::current().expect("in a task, the current task should be available"),
-            );
+            task_main_function(CurrentTask::handle());
 
             panic!("Not allowed to quit the task!");
         }
@@ -226,6 +475,7 @@ impl Task {
         name: &CStr,
         stack_size: StackType_t,
         priority: TaskPriority,
+        core_affinity: Option<CoreAffinityMask>,
         f: F,
     ) -> Result<Task, FreeRtosError>
     where
@@ -234,20 +484,30 @@ impl Task {
     {
         // SAFETY:
         // TODO: `Task::spawn_inner` has no safety requirements, it should probably not be `unsafe`.
-        unsafe { Task::spawn_inner(Box::new(f), name, stack_size, priority) }
+        unsafe { Task::spawn_inner(Box::new(f), name, stack_size, priority, core_affinity) }
     }
 
     /// Get the name of the current task.
+    ///
+    /// Fails if the name is not valid UTF-8, use [`get_name_cstr`](Self::get_name_cstr) to access the raw name.
     #[allow(clippy::result_unit_err)]
     pub fn get_name(&self) -> Result<String, ()> {
+        self.get_name_cstr()
+            .to_str()
+            .map_err(|_| ())
+            .map(String::from)
+    }
+
+    /// Get the name of the task as stored by FreeRTOS, without UTF-8 validation.
+    ///
+    /// FreeRTOS truncates names to `configMAX_TASK_NAME_LEN - 1` bytes, which may split a multi-byte character.
+    pub fn get_name_cstr(&self) -> &CStr {
         Task::assert_no_task_deletion();
         // SAFETY: Our handle is a valid undeleted task based on above guarantee.
         let name_ptr = unsafe { shim_pcTaskGetName(self.task_handle) };
-        // SAFETY: Not entirely documented, but FreeRTOS returns a valid non-null null-terminated C string.
+        // SAFETY: Not entirely documented, but FreeRTOS returns a valid non-null null-terminated C string. It is stored
+        // in the task control block, which lives as long as the task, and tasks are never deleted.
         unsafe { CStr::from_ptr(name_ptr) }
-            .to_str()
-            .map_err(|_| ())
-            .map(String::from)
     }
 
     /// Try to find the task of the current execution context.
@@ -279,6 +539,46 @@ impl Task {
         unsafe { shim_xTaskNotify(self.task_handle, value, action) };
     }
 
+    /// Notify this task and return its notification value from before the notification was applied.
+    ///
+    /// The action and the read of the previous value happen atomically, allowing read-modify-write patterns on the
+    /// notification value. Fails if `notification` is [`TaskNotification::SetValue`] and the task still has a pending
+    /// notification, in which case the value is not changed.
+    pub fn notify_and_query(&self, notification: TaskNotification) -> Result<u32, FreeRtosError> {
+        let (value, action) = notification.to_freertos();
+        let mut previous_value = 0;
+        Task::assert_no_task_deletion();
+        // SAFETY:
+        // Our handle is a valid undeleted task based on the field guarantee. Index `0` is the default notification
+        // index used by `xTaskNotifyAndQuery`, and `previous_value` is valid for writes for the duration of the call.
+        let result = unsafe {
+            xTaskGenericNotify(self.task_handle, 0, value, action, &raw mut previous_value)
+        };
+
+        if result == pdTRUE() {
+            Ok(previous_value)
+        } else {
+            Err(FreeRtosError::QueueFull)
+        }
+    }
+
+    /// Notify this task on the notification array entry at `index`.
+    ///
+    /// [`notify`](Self::notify) is equivalent to notifying index `0`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is not below `configTASK_NOTIFICATION_ARRAY_ENTRIES`.
+    pub fn notify_indexed(&self, index: UBaseType_t, notification: TaskNotification) {
+        assert_notification_index(index);
+        let (value, action) = notification.to_freertos();
+        Task::assert_no_task_deletion();
+        // SAFETY:
+        // Our handle is a valid undeleted task based on the field guarantee. The index is within the notification
+        // array, and `pulPreviousNotificationValue` may be null.
+        unsafe { xTaskGenericNotify(self.task_handle, index, value, action, null_mut()) };
+    }
+
     /// Notify this task from an interrupt.
     pub fn notify_from_isr(
         &self,
@@ -290,16 +590,44 @@ impl Task {
         Task::assert_no_task_deletion();
         // SAFETY:
         // Our handle is a valid undeleted task based on the field guarantee.
-        if unsafe {
-            shim_xTaskNotifyFromISR(
+        // `woken` points to a flag owned by `run_operation`, valid for the duration of the call.
+        if context.run_operation(|woken| unsafe {
+            shim_xTaskNotifyFromISR(self.task_handle, value, action, woken)
+        }) {
+            Ok(())
+        } else {
+            Err(FreeRtosError::QueueFull)
+        }
+    }
+
+    /// Notify this task from an interrupt and return its notification value from before the notification was
+    /// applied.
+    ///
+    /// See [`notify_and_query`](Self::notify_and_query).
+    pub fn notify_and_query_from_isr(
+        &self,
+        context: &mut InterruptContext,
+        notification: TaskNotification,
+    ) -> Result<u32, FreeRtosError> {
+        let (value, action) = notification.to_freertos();
+        let mut previous_value = 0;
+
+        Task::assert_no_task_deletion();
+        // SAFETY:
+        // Our handle is a valid undeleted task based on the field guarantee. Index `0` is the default notification
+        // index used by `xTaskNotifyAndQueryFromISR`, and `previous_value` is valid for writes for the duration of the
+        // call. `woken` points to a flag owned by `run_operation`, valid for the duration of the call.
+        if context.run_operation(|woken| unsafe {
+            xTaskGenericNotifyFromISR(
                 self.task_handle,
+                0,
                 value,
                 action,
-                context.get_task_field_mut(),
+                &raw mut previous_value,
+                woken,
             )
-        } == pdTRUE()
-        {
-            Ok(())
+        }) {
+            Ok(previous_value)
         } else {
             Err(FreeRtosError::QueueFull)
         }
@@ -334,7 +662,9 @@ impl Task {
         }
     }
 
-    /// Get the minimum amount of stack that was ever left on this task.
+    /// Get the minimum amount of stack that was ever left on this task, in words of [`StackType_t`].
+    ///
+    /// See [`get_stack_high_water_mark_bytes`](Self::get_stack_high_water_mark_bytes) for the value in bytes.
     pub fn get_stack_high_water_mark(&self) -> UBaseType_t {
         Task::assert_no_task_deletion();
         // SAFETY:
@@ -342,6 +672,100 @@ impl Task {
         unsafe { uxTaskGetStackHighWaterMark(self.task_handle) as UBaseType_t }
     }
 
+    /// Get the minimum amount of stack that was ever left on this task, in bytes.
+    pub fn get_stack_high_water_mark_bytes(&self) -> usize {
+        self.get_stack_high_water_mark() as usize * size_of::<StackType_t>()
+    }
+
+    /// Sets the thread local storage pointer at `index` of this task.
+    ///
+    /// Indices at or above `configNUM_THREAD_LOCAL_STORAGE_POINTERS` are ignored.
+    ///
+    /// # Safety
+    ///
+    /// The pointer at `index` must not be used by a [`TaskLocal`], which relies on being its only user.
+    #[cfg(configNUM_THREAD_LOCAL_STORAGE_POINTERS)]
+    pub unsafe fn set_local_storage(&self, index: usize, pointer: *mut core::ffi::c_void) {
+        Task::assert_no_task_deletion();
+        // SAFETY:
+        // Our handle is a valid undeleted task based on the field guarantee. FreeRTOS ignores out of range indices.
+        unsafe {
+            veecle_freertos_sys::bindings::vTaskSetThreadLocalStoragePointer(
+                self.task_handle,
+                index as crate::BaseType_t,
+                pointer,
+            );
+        }
+    }
+
+    /// Returns the thread local storage pointer at `index` of this task.
+    ///
+    /// Returns null for indices at or above `configNUM_THREAD_LOCAL_STORAGE_POINTERS` and for unset pointers.
+    #[cfg(configNUM_THREAD_LOCAL_STORAGE_POINTERS)]
+    pub fn get_local_storage(&self, index: usize) -> *mut core::ffi::c_void {
+        Task::assert_no_task_deletion();
+        // SAFETY:
+        // Our handle is a valid undeleted task based on the field guarantee. FreeRTOS returns null for out of range
+        // indices.
+        unsafe {
+            veecle_freertos_sys::bindings::pvTaskGetThreadLocalStoragePointer(
+                self.task_handle,
+                index as crate::BaseType_t,
+            )
+        }
+    }
+
+    /// Resumes the task if it was suspended, e.g. after [`TaskBuilder::start_suspended`].
+    ///
+    /// Resuming a task that is not suspended has no effect.
+    pub fn resume(&self) {
+        Task::assert_no_task_deletion();
+        // SAFETY:
+        // Our handle is a valid undeleted task based on the field guarantee.
+        unsafe { vTaskResume(self.task_handle) };
+    }
+
+    /// Returns the task's current priority.
+    ///
+    /// This may be an inherited priority, see `uxTaskPriorityGet`.
+    pub fn get_priority(&self) -> TaskPriority {
+        Task::assert_no_task_deletion();
+        // SAFETY:
+        // Our handle is a valid undeleted task based on the field guarantee.
+        TaskPriority(unsafe { uxTaskPriorityGet(self.task_handle) })
+    }
+
+    /// Sets the task's priority.
+    ///
+    /// Priorities above [`TaskPriority::max`] trigger `configASSERT`, or are capped if asserts are disabled.
+    pub fn set_priority(&self, priority: TaskPriority) {
+        Task::assert_no_task_deletion();
+        // SAFETY:
+        // Our handle is a valid undeleted task based on the field guarantee.
+        unsafe { vTaskPrioritySet(self.task_handle, priority.to_freertos()) };
+    }
+
+    /// Returns the cores this task is allowed to run on.
+    ///
+    /// On builds without core affinity support, tasks can only run on core 0.
+    pub fn core_affinity(&self) -> CoreAffinityMask {
+        Task::assert_no_task_deletion();
+
+        #[cfg(configUSE_CORE_AFFINITY)]
+        {
+            // SAFETY:
+            // Our handle is a valid undeleted task based on the field guarantee.
+            CoreAffinityMask(unsafe {
+                veecle_freertos_sys::bindings::vTaskCoreAffinityGet(self.task_handle)
+            })
+        }
+
+        #[cfg(not(configUSE_CORE_AFFINITY))]
+        {
+            CoreAffinityMask(1)
+        }
+    }
+
     /// # Safety
     ///
     /// This function is not thread safe, you must synchronize all usage of it, [`Task::set_id`], and
@@ -363,6 +787,100 @@ impl Task {
         // Our handle is a valid undeleted task based on the field guarantee.
         unsafe { vTaskSetTaskNumber(self.task_handle, value) };
     }
+
+    /// Returns the name, state, priorities and stack details of this task in one call.
+    ///
+    /// Computing the stack high water mark scans the task's stack, so it is only done if
+    /// `compute_stack_high_water_mark` is set.
+    #[cfg(configUSE_TRACE_FACILITY)]
+    pub fn info(&self, compute_stack_high_water_mark: bool) -> TaskInfo {
+        Task::assert_no_task_deletion();
+        system_state::task_info(self, compute_stack_high_water_mark)
+    }
+}
+
+/// Runs `f` with the current task's priority raised to at least `ceiling`, restoring the previous priority afterwards.
+///
+/// Tasks with a priority up to `ceiling` can't preempt the current task while `f` runs. If the base priority is
+/// already at or above `ceiling`, it is left unchanged. The base priority is restored once `f` returns or unwinds, so a
+/// priority temporarily inherited from a held mutex never becomes permanent.
+///
+/// # Panics
+///
+/// Panics if not called from within a task.
+pub fn with_boosted_priority<R>(ceiling: TaskPriority, f: impl FnOnce() -> R) -> R {
+    let task = Task::current().expect("priority boosting requires a task");
+    let base = base_priority(&task);
+
+    if ceiling.0 <= base.0 {
+        return f();
+    }
+
+    task.set_priority(ceiling);
+    let _restore = RestorePriority { task, base };
+    f()
+}
+
+/// Restores the base priority of a task boosted by [`with_boosted_priority`] when dropped.
+struct RestorePriority {
+    task: Task,
+    base: TaskPriority,
+}
+
+impl Drop for RestorePriority {
+    fn drop(&mut self) {
+        self.task.set_priority(self.base);
+    }
+}
+
+/// Returns the priority of `task` excluding a priority inherited from a held mutex.
+fn base_priority(task: &Task) -> TaskPriority {
+    #[cfg(configUSE_MUTEXES)]
+    {
+        Task::assert_no_task_deletion();
+        // SAFETY:
+        // The handle of a `Task` is a valid undeleted task.
+        TaskPriority(unsafe {
+            veecle_freertos_sys::bindings::uxTaskBasePriorityGet(task.task_handle)
+        })
+    }
+
+    // Without mutexes there is no priority inheritance.
+    #[cfg(not(configUSE_MUTEXES))]
+    task.get_priority()
+}
+
+/// Frequency of the run-time stats counter in Hz, zero until set with [`set_run_time_counter_frequency`].
+#[cfg(configGENERATE_RUN_TIME_STATS)]
+static RUN_TIME_COUNTER_FREQUENCY: core::sync::atomic::AtomicU32 =
+    core::sync::atomic::AtomicU32::new(0);
+
+/// Sets the frequency of the run-time stats counter, as configured by `portCONFIGURE_TIMER_FOR_RUN_TIME_STATS`.
+///
+/// The counter is set up by the port or application, so its frequency can't be queried from FreeRTOS. This enables
+/// [`CurrentTask::busy_delay_us`].
+#[cfg(configGENERATE_RUN_TIME_STATS)]
+pub fn set_run_time_counter_frequency(hz: core::num::NonZeroU32) {
+    RUN_TIME_COUNTER_FREQUENCY.store(hz.get(), core::sync::atomic::Ordering::Relaxed);
+}
+
+/// Returns the number of tasks that currently exist, including the idle and timer daemon tasks.
+///
+/// This is cheaper than a full [`system_state`] snapshot.
+pub fn count() -> UBaseType_t {
+    // SAFETY:
+    // No requirements on the caller in non-ISR contexts. The README stipulates non-ISR-safe methods to not be used in
+    // ISR contexts.
+    unsafe { uxTaskGetNumberOfTasks() }
+}
+
+/// Returns the number of entries to allocate for buffers holding one entry per task, e.g. for
+/// [`uxTaskGetSystemState`](veecle_freertos_sys::bindings::uxTaskGetSystemState).
+///
+/// Tasks may be created between this query and taking the snapshot, so the hint includes some headroom over
+/// [`count`]. Callers should still handle snapshots that don't fit.
+pub fn max_tasks_hint() -> usize {
+    count() as usize + 2
 }
 
 /// Helper methods to be performed on the task that is currently executing.
@@ -371,10 +889,50 @@ pub struct CurrentTask;
 
 impl CurrentTask {
     /// Delay the execution of the current task.
+    ///
+    /// A zero `delay` does not block, it yields to other ready tasks of the same priority like
+    /// [`yield_now`](Self::yield_now). Use [`Duration::eps`] for the shortest delay that actually blocks.
+    ///
+    /// # Panics
+    ///
+    /// In debug builds, if called with a non-zero `delay` while the scheduler is suspended.
     pub fn delay(delay: Duration) {
+        if delay.ticks() == 0 {
+            Self::yield_now();
+            return;
+        }
+
+        scheduler::debug_assert_may_block(delay);
         vTaskDelay(delay.ticks());
     }
 
+    /// Returns the task of the current execution context.
+    ///
+    /// Shorthand for [`Task::current`] where the caller is known to run inside a task.
+    ///
+    /// # Panics
+    ///
+    /// If not called from inside a task.
+    #[track_caller]
+    pub fn handle() -> Task {
+        Task::current().expect("in a task, the current task should be available")
+    }
+
+    /// Yields to other ready tasks of the same priority, the current task stays ready.
+    pub fn yield_now() {
+        taskYIELD();
+    }
+
+    /// Blocks until the next tick boundary.
+    ///
+    /// Useful to align the execution phase of the current task to the system tick before starting a periodic loop,
+    /// reducing jitter in its first iteration.
+    pub fn delay_until_next_tick() {
+        // A one tick delay expires at the next tick interrupt, regardless of how far into the current tick period we
+        // are.
+        vTaskDelay(1);
+    }
+
     pub fn suspend() {
         // SAFETY:
         // TODO(unsound): The caller must ensure this is called from inside a FreeRTOS task.
@@ -390,10 +948,116 @@ impl CurrentTask {
         unsafe { shim_ulTaskNotifyTake(clear, wait_for.ticks()) }
     }
 
-    /// Get the minimum amount of stack that was ever left on the current task.
+    /// Get the minimum amount of stack that was ever left on the current task, in words of [`StackType_t`].
     pub fn get_stack_high_water_mark() -> UBaseType_t {
         // SAFETY:
         // TODO(unsound): The caller must ensure this is called from inside a FreeRTOS task.
         unsafe { uxTaskGetStackHighWaterMark(null_mut()) }
     }
+
+    /// Busy-waits until the run-time stats counter advanced by at least `run_time_counts`.
+    ///
+    /// This is meant for delays shorter than a tick, e.g. for bit-banged protocols, where [`delay`](Self::delay) would
+    /// round down to zero. The duration of one count depends on how the port configures the run-time stats counter
+    /// (`portCONFIGURE_TIMER_FOR_RUN_TIME_STATS`), usually 10 to 100 times the tick rate.
+    ///
+    /// This burns CPU time without yielding to tasks of the same priority, only use it for very short delays. Only
+    /// available if `configGENERATE_RUN_TIME_STATS` is enabled. See [`busy_delay_us`](Self::busy_delay_us) to wait
+    /// for a duration instead.
+    #[cfg(configGENERATE_RUN_TIME_STATS)]
+    pub fn busy_delay(run_time_counts: u32) {
+        let counter = || {
+            // SAFETY: No requirements on the caller.
+            unsafe { veecle_freertos_sys::bindings::shim_portGET_RUN_TIME_COUNTER_VALUE() }
+        };
+
+        let start = counter();
+        while counter().wrapping_sub(start) < run_time_counts {
+            core::hint::spin_loop();
+        }
+    }
+
+    /// Busy-waits for at least `micros` microseconds, based on the run-time stats counter.
+    ///
+    /// Like [`busy_delay`](Self::busy_delay), but converts the duration using the counter frequency set with
+    /// [`set_run_time_counter_frequency`]. The delay is rounded up to whole counts.
+    ///
+    /// Fails with [`FreeRtosError::RunTimeCounterFrequencyUnknown`] if the frequency was not set, as the port
+    /// doesn't expose it.
+    #[cfg(configGENERATE_RUN_TIME_STATS)]
+    pub fn busy_delay_us(micros: u32) -> Result<(), FreeRtosError> {
+        let frequency = RUN_TIME_COUNTER_FREQUENCY.load(core::sync::atomic::Ordering::Relaxed);
+        if frequency == 0 {
+            return Err(FreeRtosError::RunTimeCounterFrequencyUnknown);
+        }
+
+        let counts = (u64::from(micros) * u64::from(frequency)).div_ceil(1_000_000);
+        Self::busy_delay(u32::try_from(counts).unwrap_or(u32::MAX));
+        Ok(())
+    }
+
+    /// Waits for a notification on any of the notification array entries in `indices`.
+    ///
+    /// Returns the index that was notified and its notification value, which is cleared. Earlier indices take
+    /// precedence if multiple are pending. Returns `None` if no notification arrived within `wait_for`.
+    ///
+    /// FreeRTOS can only block on a single index. With more than one index, this polls all of them once per tick,
+    /// sleeping in between, so a notification may be observed up to one tick late.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `indices` is empty or any index is not below `configTASK_NOTIFICATION_ARRAY_ENTRIES`.
+    pub fn wait_any_notification(
+        indices: &[UBaseType_t],
+        wait_for: Duration,
+    ) -> Option<(UBaseType_t, u32)> {
+        assert!(!indices.is_empty(), "no notification index to wait on");
+        indices.iter().copied().for_each(assert_notification_index);
+
+        if let [index] = indices {
+            return Self::wait_indexed_notification(*index, wait_for).map(|value| (*index, value));
+        }
+
+        let start = scheduler::get_tick_count();
+        loop {
+            for &index in indices {
+                if let Some(value) = Self::wait_indexed_notification(index, Duration::zero()) {
+                    return Some((index, value));
+                }
+            }
+
+            if wait_for != Duration::infinite()
+                && scheduler::get_tick_count().wrapping_sub(start) >= wait_for.ticks()
+            {
+                return None;
+            }
+
+            vTaskDelay(1);
+        }
+    }
+
+    /// Waits for a notification on the notification array entry at `index`, clearing its value on exit.
+    fn wait_indexed_notification(index: UBaseType_t, wait_for: Duration) -> Option<u32> {
+        let mut value = 0;
+
+        // SAFETY:
+        // TODO(unsound): The caller must ensure this is called from inside a FreeRTOS task.
+        // The index is within the notification array, and a writable pointer to `value` is passed as the
+        // `pulNotificationValue` argument.
+        if unsafe { xTaskGenericNotifyWait(index, 0, u32::MAX, &raw mut value, wait_for.ticks()) }
+            == pdTRUE()
+        {
+            Some(value)
+        } else {
+            None
+        }
+    }
+}
+
+/// Asserts that `index` is a valid task notification array index.
+fn assert_notification_index(index: UBaseType_t) {
+    assert!(
+        index < UBaseType_t::from(configTASK_NOTIFICATION_ARRAY_ENTRIES),
+        "notification index {index} out of range"
+    );
 }
@@ -0,0 +1,31 @@
+use crate::scheduler::with_scheduler_suspended;
+use crate::{Task, TaskNotification};
+
+/// A fixed set of tasks that can be notified together, e.g. by a shutdown coordinator.
+#[derive(Debug, Clone)]
+pub struct NotifyGroup<const N: usize> {
+    tasks: [Task; N],
+}
+
+impl<const N: usize> NotifyGroup<N> {
+    /// Creates a group of `tasks`.
+    pub const fn new(tasks: [Task; N]) -> Self {
+        Self { tasks }
+    }
+
+    /// Returns the tasks of this group.
+    pub fn tasks(&self) -> &[Task; N] {
+        &self.tasks
+    }
+
+    /// Sends `notification` to every task of the group.
+    ///
+    /// The scheduler is suspended while notifying, so woken tasks are only rescheduled once all tasks were notified.
+    pub fn notify_all(&self, notification: TaskNotification) {
+        with_scheduler_suspended(|| {
+            for task in &self.tasks {
+                task.notify(notification);
+            }
+        });
+    }
+}
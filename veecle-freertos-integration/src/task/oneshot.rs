@@ -0,0 +1,53 @@
+use core::marker::PhantomData;
+
+use crate::units::Duration;
+use crate::{FreeRtosError, Task, TaskNotification};
+
+/// Creates a single-value channel to the current task based on direct-to-task notifications.
+///
+/// The [`NotifySender`] can be moved to any task and sends one `u32` to the [`NotifyReceiver`], which stays on the
+/// current task. This is lighter than a [`Queue`](crate::Queue), but shares the task's default notification with other
+/// users, e.g. [`block_on_future`](super::block_on_future), so those must not be used concurrently.
+///
+/// Fails if not called from a task.
+pub fn oneshot() -> Result<(NotifySender, NotifyReceiver), FreeRtosError> {
+    let task = Task::current()?;
+
+    Ok((
+        NotifySender { task: task.clone() },
+        NotifyReceiver {
+            task,
+            not_send: PhantomData,
+        },
+    ))
+}
+
+/// Sending half of a [`oneshot`] channel.
+#[derive(Debug)]
+pub struct NotifySender {
+    task: Task,
+}
+
+impl NotifySender {
+    /// Sends `value` to the receiver, overwriting any value that was not received yet.
+    pub fn send(self, value: u32) {
+        self.task.notify(TaskNotification::OverwriteValue(value));
+    }
+}
+
+/// Receiving half of a [`oneshot`] channel, bound to the task that created it.
+#[derive(Debug)]
+pub struct NotifyReceiver {
+    task: Task,
+    /// Notifications can only be waited for by the task that created the channel.
+    not_send: PhantomData<*const ()>,
+}
+
+impl NotifyReceiver {
+    /// Waits up to `timeout` for the value and returns it.
+    ///
+    /// Fails with [`FreeRtosError::Timeout`] if no value was sent in time.
+    pub fn recv(&self, timeout: Duration) -> Result<u32, FreeRtosError> {
+        self.task.wait_for_notification(0, u32::MAX, timeout)
+    }
+}
@@ -0,0 +1,73 @@
+use alloc::boxed::Box;
+use core::any::Any;
+use std::sync::OnceLock;
+
+use veecle_freertos_sys::bindings::UBaseType_t;
+
+use crate::units::Duration;
+use crate::{FreeRtosError, Queue, Task};
+
+/// Queue of the installed [`PanicMonitor`], shared by all handles and the task panic hook.
+static REPORTS: OnceLock<Queue<(Task, &'static str)>> = OnceLock::new();
+
+/// Collects the panics of tasks started with [`TaskBuilder::catch_panics`](crate::TaskBuilder::catch_panics), so a
+/// supervisor task can report them centrally.
+///
+/// Each report holds the panicked task and its panic message. Messages of panics with formatting arguments aren't
+/// `'static` and are reported as `"<formatted panic message>"`, other payloads as `"<unknown panic payload>"`.
+///
+/// Requires building with `panic = "unwind"`, otherwise panics abort the program as usual.
+#[derive(Debug, Clone)]
+pub struct PanicMonitor {
+    reports: Queue<(Task, &'static str)>,
+}
+
+impl PanicMonitor {
+    /// Installs the monitor as the task panic hook, buffering up to `depth` reports.
+    ///
+    /// This replaces a hook set with [`hooks::set_on_task_panic`](crate::hooks::set_on_task_panic). If a monitor was
+    /// already installed, a handle to it is returned and `depth` is ignored. A panicked task waits for space in the
+    /// queue before it is suspended, so no report is lost.
+    pub fn install(depth: UBaseType_t) -> Result<Self, FreeRtosError> {
+        let reports = match REPORTS.get() {
+            Some(reports) => reports,
+            None => {
+                let reports = Queue::new(depth)?;
+                // Another task may have installed a monitor in the meantime, its queue wins.
+                let _ = REPORTS.set(reports);
+                REPORTS.get().expect("the reports queue was just set")
+            }
+        };
+
+        crate::hooks::set_on_task_panic(report_panic);
+
+        Ok(Self {
+            reports: reports.clone(),
+        })
+    }
+
+    /// Waits for the next panic report for up to `max_wait`, returns `None` if no task panicked in that time.
+    pub fn receive(&self, max_wait: Duration) -> Option<(Task, &'static str)> {
+        self.reports.receive(max_wait).ok()
+    }
+
+    /// Returns the number of reports not yet received.
+    pub fn pending(&self) -> UBaseType_t {
+        self.reports.messages_waiting()
+    }
+}
+
+/// Task panic hook sending a report to the installed [`PanicMonitor`].
+fn report_panic(task: Task, payload: Box<dyn Any + Send>) {
+    let message = if let Some(message) = payload.downcast_ref::<&'static str>() {
+        message
+    } else if payload.is::<alloc::string::String>() {
+        "<formatted panic message>"
+    } else {
+        "<unknown panic payload>"
+    };
+
+    if let Some(reports) = REPORTS.get() {
+        let _ = reports.send((task, message), Duration::infinite());
+    }
+}
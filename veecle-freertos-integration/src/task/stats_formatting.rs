@@ -0,0 +1,51 @@
+use alloc::string::String;
+use alloc::vec;
+use core::ffi::CStr;
+
+use veecle_freertos_sys::bindings::configMAX_TASK_NAME_LEN;
+
+/// Estimated length of a formatted line per task, excluding the task's name.
+const LINE_LENGTH_ESTIMATE: usize = 40;
+
+/// Calls `format` with a buffer sized for the current number of tasks and returns the written string.
+///
+/// `format` must write a null-terminated string of at most the given length into the buffer.
+fn format_tasks(format: impl FnOnce(*mut core::ffi::c_char, usize)) -> String {
+    // Tasks may be created between counting and formatting them, the hint leaves some headroom. The output is truncated
+    // if it still doesn't fit.
+    let line_length = configMAX_TASK_NAME_LEN as usize + LINE_LENGTH_ESTIMATE;
+    let mut buffer = vec![0u8; super::max_tasks_hint() * line_length + 1];
+
+    format(buffer.as_mut_ptr().cast(), buffer.len());
+
+    CStr::from_bytes_until_nul(&buffer)
+        .expect("FreeRTOS should null-terminate the output")
+        .to_string_lossy()
+        .into_owned()
+}
+
+/// Returns a human-readable table of all tasks, as formatted by `vTaskListTasks`.
+///
+/// Each line lists a task's name, state, priority, stack high water mark and number. This function suspends the
+/// scheduler while collecting the data, so it is intended for debugging only.
+#[cfg(configUSE_TRACE_FACILITY)]
+pub fn list() -> String {
+    format_tasks(|buffer, length| {
+        // SAFETY:
+        // `buffer` is valid for writes of `length` bytes, FreeRTOS writes at most that many.
+        unsafe { veecle_freertos_sys::bindings::vTaskListTasks(buffer, length) }
+    })
+}
+
+/// Returns a human-readable table of the run time of all tasks, as formatted by `vTaskGetRunTimeStatistics`.
+///
+/// Each line lists a task's name, its absolute run time in run-time counter units and its percentage of the total run
+/// time. This function suspends the scheduler while collecting the data, so it is intended for debugging only.
+#[cfg(all(configUSE_TRACE_FACILITY, configGENERATE_RUN_TIME_STATS))]
+pub fn runtime_stats() -> String {
+    format_tasks(|buffer, length| {
+        // SAFETY:
+        // `buffer` is valid for writes of `length` bytes, FreeRTOS writes at most that many.
+        unsafe { veecle_freertos_sys::bindings::vTaskGetRunTimeStatistics(buffer, length) }
+    })
+}
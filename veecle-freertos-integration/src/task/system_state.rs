@@ -0,0 +1,175 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::ffi::CStr;
+use core::mem::MaybeUninit;
+
+use veecle_freertos_sys::bindings::{
+    StackType_t, TaskStatus_t, UBaseType_t, eTaskState, eTaskState_eBlocked, eTaskState_eDeleted,
+    eTaskState_eInvalid, eTaskState_eReady, eTaskState_eRunning, eTaskState_eSuspended, pdFALSE,
+    pdTRUE, uxTaskGetSystemState, vTaskGetInfo,
+};
+
+use crate::{Task, TaskPriority};
+
+/// State of a task as reported by FreeRTOS.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum TaskState {
+    /// The task is currently executing.
+    Running,
+    /// The task is ready to run but a task of equal or higher priority is running.
+    Ready,
+    /// The task is waiting for a timeout or an event.
+    Blocked,
+    /// The task is suspended or waiting indefinitely for an event.
+    Suspended,
+    /// The task has been deleted but its memory has not been freed yet.
+    Deleted,
+    /// The task state could not be determined.
+    Invalid,
+}
+
+impl From<eTaskState> for TaskState {
+    fn from(state: eTaskState) -> Self {
+        match state {
+            eTaskState_eRunning => TaskState::Running,
+            eTaskState_eReady => TaskState::Ready,
+            eTaskState_eBlocked => TaskState::Blocked,
+            eTaskState_eSuspended => TaskState::Suspended,
+            eTaskState_eDeleted => TaskState::Deleted,
+            _ => TaskState::Invalid,
+        }
+    }
+}
+
+/// Snapshot of a task's status, see [`system_state`].
+#[derive(Debug, Clone)]
+pub struct TaskStatus {
+    /// The task this status describes.
+    pub task: Task,
+    /// The task's name.
+    pub name: String,
+    /// The task's unique number, assigned by FreeRTOS on creation.
+    pub task_number: UBaseType_t,
+    /// The task's state at the time of the snapshot.
+    pub state: TaskState,
+    /// The task's current, possibly inherited, priority.
+    pub priority: TaskPriority,
+    /// The total run time allocated to the task, in run-time counter units.
+    ///
+    /// Only meaningful if `configGENERATE_RUN_TIME_STATS` is enabled.
+    pub run_time_counter: u32,
+    /// The minimum amount of stack, in words, that was ever left for the task.
+    pub stack_high_water_mark: StackType_t,
+}
+
+impl From<&TaskStatus_t> for TaskStatus {
+    fn from(status: &TaskStatus_t) -> Self {
+        TaskStatus {
+            // SAFETY:
+            // The handle was just reported by FreeRTOS, and tasks can't be deleted, see
+            // `Task::assert_no_task_deletion`.
+            task: unsafe { Task::from_raw_handle(status.xHandle) },
+            // SAFETY:
+            // FreeRTOS reports a pointer to the task's null-terminated name, which lives as long as the task.
+            name: unsafe { CStr::from_ptr(status.pcTaskName) }
+                .to_string_lossy()
+                .into_owned(),
+            task_number: status.xTaskNumber,
+            state: status.eCurrentState.into(),
+            priority: TaskPriority(status.uxCurrentPriority),
+            run_time_counter: status.ulRunTimeCounter,
+            stack_high_water_mark: status.usStackHighWaterMark,
+        }
+    }
+}
+
+/// Details of a single task, see [`Task::info`].
+#[derive(Debug, Clone)]
+pub struct TaskInfo {
+    /// The task's name.
+    pub name: String,
+    /// The task's state at the time of the query.
+    pub state: TaskState,
+    /// The task's current, possibly inherited, priority.
+    pub priority: TaskPriority,
+    /// The priority the task returns to after priority inheritance ends.
+    ///
+    /// Only meaningful if `configUSE_MUTEXES` is enabled.
+    pub base_priority: TaskPriority,
+    /// The lowest address of the task's stack.
+    pub stack_base: *mut StackType_t,
+    /// The minimum amount of stack, in words, that was ever left for the task.
+    ///
+    /// `None` unless requested, as computing it scans the task's stack.
+    pub stack_high_water_mark: Option<StackType_t>,
+}
+
+/// Queries the details of `task` with `vTaskGetInfo`.
+pub(super) fn task_info(task: &Task, compute_stack_high_water_mark: bool) -> TaskInfo {
+    let mut status = MaybeUninit::<TaskStatus_t>::uninit();
+
+    // SAFETY:
+    // The handle is valid as tasks can't be deleted, see `Task::assert_no_task_deletion`. `vTaskGetInfo` fills the
+    // whole struct, computing the state as `eInvalid` is passed.
+    let status = unsafe {
+        vTaskGetInfo(
+            task.raw_handle(),
+            status.as_mut_ptr(),
+            if compute_stack_high_water_mark {
+                pdTRUE()
+            } else {
+                pdFALSE()
+            },
+            eTaskState_eInvalid,
+        );
+        status.assume_init()
+    };
+
+    TaskInfo {
+        // SAFETY:
+        // FreeRTOS reports a pointer to the task's null-terminated name, which lives as long as the task.
+        name: unsafe { CStr::from_ptr(status.pcTaskName) }
+            .to_string_lossy()
+            .into_owned(),
+        state: status.eCurrentState.into(),
+        priority: TaskPriority(status.uxCurrentPriority),
+        base_priority: TaskPriority(status.uxBasePriority),
+        stack_base: status.pxStackBase,
+        stack_high_water_mark: compute_stack_high_water_mark.then_some(status.usStackHighWaterMark),
+    }
+}
+
+/// Returns the status of every task in the system.
+///
+/// This function suspends the scheduler while collecting the data, so it is intended for debugging only.
+pub fn system_state() -> Vec<TaskStatus> {
+    Task::assert_no_task_deletion();
+
+    loop {
+        // Tasks may be created between counting and collecting them, the hint leaves some headroom.
+        let capacity = super::max_tasks_hint();
+        let mut statuses = Vec::<TaskStatus_t>::with_capacity(capacity);
+
+        // SAFETY:
+        // The array has capacity for `capacity` entries, FreeRTOS writes at most that many. Passing a null total
+        // run-time pointer is allowed.
+        let written = unsafe {
+            uxTaskGetSystemState(
+                statuses.as_mut_ptr(),
+                capacity as UBaseType_t,
+                core::ptr::null_mut(),
+            )
+        };
+
+        // Zero entries are written if the array is too small, retry with an updated task count.
+        if written == 0 {
+            continue;
+        }
+
+        // SAFETY:
+        // `uxTaskGetSystemState` initialized the first `written` entries.
+        unsafe { statuses.set_len(written as usize) };
+
+        return statuses.iter().map(TaskStatus::from).collect();
+    }
+}
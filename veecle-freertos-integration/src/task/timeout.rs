@@ -0,0 +1,42 @@
+use core::fmt::{self, Display};
+use core::future::{Future, poll_fn};
+use core::pin::{Pin, pin};
+use core::task::Poll;
+
+use crate::units::Duration;
+
+/// Error returned by [`with_timeout`] if the timeout elapsed before the future completed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Timeout;
+
+impl core::error::Error for Timeout {}
+
+impl Display for Timeout {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("future timed out")
+    }
+}
+
+/// Runs `future` until it completes or `timeout` elapses, measured from this call.
+///
+/// The timeout is driven by a [`sleep`](crate::sleep) future, whose timer is deleted as soon as the returned future
+/// completes or is dropped.
+pub fn with_timeout<F: Future>(
+    timeout: Duration,
+    future: F,
+) -> impl Future<Output = Result<F::Output, Timeout>> {
+    let mut sleep = crate::sleep(timeout);
+    async move {
+        let mut future = pin!(future);
+        poll_fn(|cx| {
+            if let Poll::Ready(output) = future.as_mut().poll(cx) {
+                return Poll::Ready(Ok(output));
+            }
+            if Pin::new(&mut sleep).poll(cx).is_ready() {
+                return Poll::Ready(Err(Timeout));
+            }
+            Poll::Pending
+        })
+        .await
+    }
+}
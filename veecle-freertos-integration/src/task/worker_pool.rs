@@ -0,0 +1,78 @@
+use alloc::boxed::Box;
+use core::fmt::{self, Debug};
+
+use veecle_freertos_sys::bindings::UBaseType_t;
+
+use crate::units::Duration;
+use crate::{FreeRtosError, Queue, Task, TaskPriority};
+
+/// A job executed by a [`WorkerPool`].
+pub type Job = Box<dyn FnOnce() + Send>;
+
+/// A fixed number of worker tasks running submitted closures.
+///
+/// The closures are boxed and sent through a shared queue, each worker takes the next closure once it finished the
+/// previous one. Clones submit to the same workers.
+#[derive(Clone)]
+pub struct WorkerPool {
+    jobs: Queue<Job>,
+}
+
+impl WorkerPool {
+    /// Starts `workers` tasks at `priority`, sharing a queue holding up to `queue_depth` pending closures.
+    ///
+    /// The worker tasks run forever, even if all handles to the pool are dropped.
+    pub fn new(
+        workers: usize,
+        queue_depth: UBaseType_t,
+        priority: TaskPriority,
+    ) -> Result<Self, FreeRtosError> {
+        let jobs = Queue::<Job>::new(queue_depth)?;
+
+        for _ in 0..workers {
+            let receiver = jobs.clone();
+            Task::new()
+                .name(c"worker")
+                .priority(priority)
+                .start(move |_| {
+                    loop {
+                        if let Ok(job) = receiver.receive(Duration::infinite()) {
+                            job();
+                        }
+                    }
+                })?;
+        }
+
+        Ok(Self { jobs })
+    }
+
+    /// Submits `f` to be run by the next free worker, waiting for space in the queue.
+    pub fn submit(&self, f: impl FnOnce() + Send + 'static) {
+        let mut job: Job = Box::new(f);
+        while let Err(returned) = self.jobs.send(job, Duration::infinite()) {
+            job = returned;
+        }
+    }
+
+    /// Submits `f` to be run by the next free worker without waiting.
+    ///
+    /// Returns [`FreeRtosError::QueueFull`] and drops `f` if the queue is full.
+    pub fn try_submit(&self, f: impl FnOnce() + Send + 'static) -> Result<(), FreeRtosError> {
+        self.jobs
+            .send(Box::new(f), Duration::zero())
+            .map_err(|_| FreeRtosError::QueueFull)
+    }
+
+    /// Returns the number of submitted closures not yet taken by a worker.
+    pub fn pending(&self) -> UBaseType_t {
+        self.jobs.messages_waiting()
+    }
+}
+
+impl Debug for WorkerPool {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WorkerPool")
+            .field("pending", &self.pending())
+            .finish_non_exhaustive()
+    }
+}
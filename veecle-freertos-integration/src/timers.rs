@@ -1,16 +1,28 @@
 use alloc::boxed::Box;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::cell::UnsafeCell;
 use core::ffi::CStr;
+use core::fmt::{self, Debug};
+use core::future::Future;
 use core::marker::PhantomData;
+use core::pin::Pin;
 use core::ptr;
+use core::sync::atomic::{AtomicBool, Ordering};
+use core::task::{Context, Poll};
+
+use atomic_waker::AtomicWaker;
 
 use veecle_freertos_sys::bindings::{
     TickType_t, TimerHandle_t, pdFALSE, pdTRUE, pvTimerGetTimerID, shim_xTimerChangePeriod,
-    shim_xTimerDelete, shim_xTimerStart, shim_xTimerStartFromISR, shim_xTimerStop, xTimerCreate,
-    xTimerPendFunctionCall,
+    shim_xTimerChangePeriodFromISR, shim_xTimerDelete, shim_xTimerStart, shim_xTimerStartFromISR,
+    shim_xTimerStop, shim_xTimerStopFromISR, vTimerSetReloadMode, xTimerCreate,
+    xTimerGetReloadMode, xTimerPendFunctionCall, xTimerPendFunctionCallFromISR,
 };
 
+use crate::scheduler::with_scheduler_suspended;
 use crate::units::Duration;
-use crate::{FreeRtosError, InterruptContext};
+use crate::{FreeRtosError, InterruptContext, scheduler};
 
 /// Wraps the reference to a FreeRTOS's timer handle, exposing an API to safely communicate with FreeRTOS
 /// and perform actions over the corresponding [Timer].
@@ -22,57 +34,147 @@ impl TimerHandle {
     const MS_TIMEOUT: TickType_t = 50;
 
     /// Start the timer.
+    ///
+    /// Fails with [`FreeRtosError::TimerCommandQueueFull`] if the command could not be sent to the timer service task
+    /// within the block time.
     pub fn start(&self) -> Result<(), FreeRtosError> {
         // SAFETY:
         // Our handle is a valid undeleted timer based on the field guarantee.
         if unsafe { shim_xTimerStart(self.as_ptr(), Self::block_time()) } == pdTRUE() {
             Ok(())
         } else {
-            Err(FreeRtosError::Timeout)
+            Err(FreeRtosError::TimerCommandQueueFull)
         }
     }
 
     /// Start the timer from an interrupt.
+    ///
+    /// Fails with [`FreeRtosError::TimerCommandQueueFull`] if the timer command queue is full.
     pub fn start_from_isr(&self, context: &mut InterruptContext) -> Result<(), FreeRtosError> {
         // SAFETY:
         // Our handle is a valid undeleted timer based on the field guarantee.
-        if unsafe { shim_xTimerStartFromISR(self.as_ptr(), context.get_task_field_mut()) }
-            == pdTRUE()
-        {
+        // `woken` points to a flag owned by `run_operation`, valid for the duration of the call.
+        if context.run_operation(|woken| unsafe { shim_xTimerStartFromISR(self.as_ptr(), woken) }) {
             Ok(())
         } else {
-            Err(FreeRtosError::QueueSendTimeout)
+            Err(FreeRtosError::TimerCommandQueueFull)
         }
     }
 
     /// Stop the timer.
+    ///
+    /// Fails with [`FreeRtosError::TimerCommandQueueFull`] if the command could not be sent to the timer service task
+    /// within the block time.
     pub fn stop(&self) -> Result<(), FreeRtosError> {
         // SAFETY:
         // Our handle is a valid undeleted timer based on the field guarantee.
         if unsafe { shim_xTimerStop(self.as_ptr(), Self::block_time()) } == pdTRUE() {
             Ok(())
         } else {
-            Err(FreeRtosError::Timeout)
+            Err(FreeRtosError::TimerCommandQueueFull)
+        }
+    }
+
+    /// Stop the timer from an interrupt.
+    ///
+    /// Fails with [`FreeRtosError::TimerCommandQueueFull`] if the timer command queue is full.
+    pub fn stop_from_isr(&self, context: &mut InterruptContext) -> Result<(), FreeRtosError> {
+        // SAFETY:
+        // Our handle is a valid undeleted timer based on the field guarantee.
+        // `woken` points to a flag owned by `run_operation`, valid for the duration of the call.
+        if context.run_operation(|woken| unsafe { shim_xTimerStopFromISR(self.as_ptr(), woken) }) {
+            Ok(())
+        } else {
+            Err(FreeRtosError::TimerCommandQueueFull)
         }
     }
 
     /// Change the period of the timer.
+    ///
+    /// Fails with [`FreeRtosError::TimerCommandQueueFull`] if the command could not be sent to the timer service task
+    /// within the block time.
     pub fn change_period(&self, new_period: Duration) -> Result<(), FreeRtosError> {
+        self.change_period_with_block_time(new_period, Self::block_time())
+    }
+
+    /// Change the period of the timer from an interrupt.
+    ///
+    /// Like [`change_period`](Self::change_period), this also starts the timer if it was stopped.
+    ///
+    /// Fails with [`FreeRtosError::TimerCommandQueueFull`] if the timer command queue is full.
+    pub fn change_period_from_isr(
+        &self,
+        new_period: Duration,
+        context: &mut InterruptContext,
+    ) -> Result<(), FreeRtosError> {
         if new_period.ticks() == 0 {
             return Err(FreeRtosError::ZeroDuration);
         }
         // SAFETY:
         // Our handle is a valid undeleted timer based on the field guarantee. This call is unreachable if `new_period`
         // equals zero.
-        if unsafe { shim_xTimerChangePeriod(self.as_ptr(), new_period.ticks(), Self::block_time()) }
+        // `woken` points to a flag owned by `run_operation`, valid for the duration of the call.
+        if context.run_operation(|woken| unsafe {
+            shim_xTimerChangePeriodFromISR(self.as_ptr(), new_period.ticks(), woken)
+        }) {
+            Ok(())
+        } else {
+            Err(FreeRtosError::TimerCommandQueueFull)
+        }
+    }
+
+    /// Like [`change_period`](Self::change_period), but without waiting for space in the timer command queue.
+    ///
+    /// Meant for timer callbacks, which run on the timer service task and must not block.
+    pub(crate) fn change_period_from_callback(
+        &self,
+        new_period: Duration,
+    ) -> Result<(), FreeRtosError> {
+        self.change_period_with_block_time(new_period, 0)
+    }
+
+    fn change_period_with_block_time(
+        &self,
+        new_period: Duration,
+        block_time: TickType_t,
+    ) -> Result<(), FreeRtosError> {
+        if new_period.ticks() == 0 {
+            return Err(FreeRtosError::ZeroDuration);
+        }
+        // SAFETY:
+        // Our handle is a valid undeleted timer based on the field guarantee. This call is unreachable if `new_period`
+        // equals zero.
+        if unsafe { shim_xTimerChangePeriod(self.as_ptr(), new_period.ticks(), block_time) }
             == pdTRUE()
         {
             Ok(())
         } else {
-            Err(FreeRtosError::Timeout)
+            Err(FreeRtosError::TimerCommandQueueFull)
         }
     }
 
+    /// Sets whether the timer automatically restarts each time it expires, or only expires once.
+    ///
+    /// Unlike [`start`](Self::start) and [`stop`](Self::stop) this takes effect immediately, without going through the
+    /// timer service task.
+    pub fn set_reload_mode(&self, auto_reload: bool) {
+        // SAFETY:
+        // Our handle is a valid undeleted timer based on the field guarantee.
+        unsafe {
+            vTimerSetReloadMode(
+                self.as_ptr(),
+                if auto_reload { pdTRUE() } else { pdFALSE() },
+            )
+        }
+    }
+
+    /// Returns `true` if the timer automatically restarts each time it expires.
+    pub fn reload_mode(&self) -> bool {
+        // SAFETY:
+        // Our handle is a valid undeleted timer based on the field guarantee.
+        unsafe { xTimerGetReloadMode(self.as_ptr()) != pdFALSE() }
+    }
+
     #[inline]
     fn as_ptr(&self) -> TimerHandle_t {
         self.0
@@ -92,15 +194,142 @@ impl TimerHandle {
     }
 }
 
+/// Defers `f` to the timer service task, waiting up to `block_time` for space in the timer command queue.
+///
+/// This moves work into a task context without a dedicated task. `f` runs after all previously queued timer commands
+/// and must not block, like timer callbacks.
+pub fn pend_function_call<F>(f: F, block_time: Duration) -> Result<(), FreeRtosError>
+where
+    F: FnOnce() + Send + 'static,
+{
+    extern "C" fn call<F: FnOnce()>(f: *mut core::ffi::c_void, _: u32) {
+        // SAFETY:
+        // The pointer was created from a `Box<F>` in `pend_function_call`, the timer service task calls this
+        // function exactly once for it.
+        let f = unsafe { Box::from_raw(f.cast::<F>()) };
+        f();
+    }
+
+    let f = Box::into_raw(Box::new(f));
+
+    // SAFETY:
+    // `call::<F>` matches the type of the boxed closure passed as `pvParameter1`, which stays valid until `call`
+    // takes back ownership of it.
+    if unsafe { xTimerPendFunctionCall(Some(call::<F>), f.cast(), 0, block_time.ticks()) }
+        == pdTRUE()
+    {
+        Ok(())
+    } else {
+        // SAFETY: Pending the call failed so we retain ownership of the closure.
+        drop(unsafe { Box::from_raw(f) });
+        Err(FreeRtosError::TimerCommandQueueFull)
+    }
+}
+
+/// Defers `f`, called with `parameter`, from an interrupt to the timer service task.
+///
+/// Unlike [`pend_function_call`] this takes a function pointer, as interrupts can't allocate to store a closure.
+pub fn pend_function_call_from_isr(
+    context: &mut InterruptContext,
+    f: fn(u32),
+    parameter: u32,
+) -> Result<(), FreeRtosError> {
+    extern "C" fn call(f: *mut core::ffi::c_void, parameter: u32) {
+        // SAFETY:
+        // The pointer is the `fn(u32)` passed as `pvParameter1` in `pend_function_call_from_isr`.
+        let f = unsafe { core::mem::transmute::<*mut core::ffi::c_void, fn(u32)>(f) };
+        f(parameter);
+    }
+
+    // SAFETY:
+    // The function pointer passed as `pvParameter1` is valid forever.
+    // `woken` points to a flag owned by `run_operation`, valid for the duration of the call.
+    if context.run_operation(|woken| unsafe {
+        xTimerPendFunctionCallFromISR(Some(call), f as *mut core::ffi::c_void, parameter, woken)
+    }) {
+        Ok(())
+    } else {
+        Err(FreeRtosError::TimerCommandQueueFull)
+    }
+}
+
+/// Deletes a timer detached with [`Timer::detach_tracked`] and frees its callback.
+///
+/// Returns `false` if `handle` is not a tracked timer, e.g. because it was already deleted.
+///
+/// # Panics
+///
+/// If deleting the timer fails, like dropping a [`Timer`].
+pub fn delete_tracked_timer(handle: TimerHandle) -> bool {
+    match TRACKED_TIMERS.remove(handle) {
+        Some(delete) => {
+            delete(handle);
+            true
+        }
+        None => false,
+    }
+}
+
+/// Timers detached with [`Timer::detach_tracked`].
+static TRACKED_TIMERS: TrackedTimers = TrackedTimers::new();
+
+/// Deletes a tracked timer, knowing the type of its callback.
+type DeleteTrackedFn = fn(TimerHandle);
+
+/// Registry of tracked timers, each with the function deleting it as its callback type is erased.
+///
+/// Access is synchronized by suspending the scheduler, so the registry must not be used from ISR contexts.
+struct TrackedTimers {
+    timers: UnsafeCell<Vec<(TimerHandle_t, DeleteTrackedFn)>>,
+}
+
+// SAFETY: All accesses to `timers` happen with the scheduler suspended, so no two tasks can access it concurrently.
+unsafe impl Sync for TrackedTimers {}
+
+impl TrackedTimers {
+    /// Creates an empty registry.
+    const fn new() -> Self {
+        Self {
+            timers: UnsafeCell::new(Vec::new()),
+        }
+    }
+
+    /// Records `handle` with the function deleting it.
+    fn insert(&self, handle: TimerHandle, delete: DeleteTrackedFn) {
+        with_scheduler_suspended(|| {
+            // SAFETY: The scheduler is suspended, so this is the only access to `timers`.
+            unsafe { &mut *self.timers.get() }.push((handle.as_ptr(), delete));
+        });
+    }
+
+    /// Removes `handle` and returns the function deleting it, if it was recorded.
+    fn remove(&self, handle: TimerHandle) -> Option<DeleteTrackedFn> {
+        with_scheduler_suspended(|| {
+            // SAFETY: The scheduler is suspended, so this is the only access to `timers`.
+            let timers = unsafe { &mut *self.timers.get() };
+            let index = timers
+                .iter()
+                .position(|(tracked, _)| *tracked == handle.as_ptr())?;
+            Some(timers.swap_remove(index).1)
+        })
+    }
+}
+
+/// Type-erased timer callback, used by timers created with [`Timer::periodic_with_data`] and [`Timer::once_with_data`].
+pub type BoxedTimerCallback = Box<dyn FnMut(TimerHandle) + Send>;
+
 /// A FreeRTOS software timer.
 ///
 /// Note that all operations on a timer are processed by a FreeRTOS internal task
 /// that receives messages in a queue. Every operation has an associated waiting time
 /// for that queue to get unblocked.
+///
+/// The callback is only ever invoked from that timer service task, one expiry at a time, so it may mutate its
+/// captured state.
 #[derive(Debug)]
 pub struct Timer<F>
 where
-    F: Fn(TimerHandle) + Send + 'static,
+    F: FnMut(TimerHandle) + Send + 'static,
 {
     handle: TimerHandle,
     callback: PhantomData<F>,
@@ -108,7 +337,7 @@ where
 
 impl<F> Timer<F>
 where
-    F: Fn(TimerHandle) + Send + 'static,
+    F: FnMut(TimerHandle) + Send + 'static,
 {
     /// Creates a new [`Timer`] which ticks periodically.
     pub fn periodic(
@@ -143,6 +372,23 @@ where
         core::mem::forget(self);
     }
 
+    /// Like [`detach`](Self::detach), but records the timer so it can later be deleted, freeing its callback, with
+    /// [`delete_tracked_timer`].
+    pub fn detach_tracked(self) -> TimerHandle {
+        let handle = self.handle;
+        TRACKED_TIMERS.insert(handle, Self::delete_tracked);
+        core::mem::forget(self);
+        handle
+    }
+
+    /// Deletes a timer detached with [`detach_tracked`](Self::detach_tracked).
+    fn delete_tracked(handle: TimerHandle) {
+        drop(Self {
+            handle,
+            callback: PhantomData,
+        });
+    }
+
     /// Tries to create a timer with the given strategy.
     fn spawn(
         name: Option<&'static CStr>,
@@ -193,8 +439,10 @@ where
         // SAFETY:
         // The callback's pointer is obtained during spawn and assigned as the timer's id, therefore the
         // pointer belongs to a valid callback.
+        // The callback is only accessed from the timer service task, which runs one timer callback at a time and only
+        // drops the callback after the timer has been deleted, so this is the only reference to it.
         let callback =
-            unsafe { handle.id().cast::<F>().as_ref() }.expect("callback should not be null");
+            unsafe { handle.id().cast::<F>().as_mut() }.expect("callback should not be null");
         callback(handle);
     }
 
@@ -213,9 +461,52 @@ where
     }
 }
 
+impl Timer<BoxedTimerCallback> {
+    /// Creates a new [`Timer`] which ticks periodically, passing a reference to `data` to every `callback` invocation.
+    ///
+    /// `data` is stored alongside the callback and dropped with the timer, so state shared with other tasks can be
+    /// passed without statics, e.g. as an `Arc`.
+    pub fn periodic_with_data<D, C>(
+        name: Option<&'static CStr>,
+        period: Duration,
+        data: D,
+        callback: C,
+    ) -> Result<Self, FreeRtosError>
+    where
+        D: Send + 'static,
+        C: Fn(TimerHandle, &D) + Send + 'static,
+    {
+        Self::periodic(
+            name,
+            period,
+            Box::new(move |handle| callback(handle, &data)),
+        )
+    }
+
+    /// Creates a [`Timer`] that ticks once, passing a reference to `data` to `callback`.
+    ///
+    /// See [`periodic_with_data`](Self::periodic_with_data).
+    pub fn once_with_data<D, C>(
+        name: Option<&'static CStr>,
+        period: Duration,
+        data: D,
+        callback: C,
+    ) -> Result<Self, FreeRtosError>
+    where
+        D: Send + 'static,
+        C: Fn(TimerHandle, &D) + Send + 'static,
+    {
+        Self::once(
+            name,
+            period,
+            Box::new(move |handle| callback(handle, &data)),
+        )
+    }
+}
+
 impl<F> Drop for Timer<F>
 where
-    F: Fn(TimerHandle) + Send + 'static,
+    F: FnMut(TimerHandle) + Send + 'static,
 {
     fn drop(&mut self) {
         // get timer's id before deleting it.
@@ -243,3 +534,112 @@ where
         assert_eq!(result, pdTRUE(), "drop callback scheduling has failed");
     }
 }
+
+/// Returns a future that completes once `duration` has elapsed, measured from this call.
+///
+/// Unlike [`CurrentTask::delay`](crate::CurrentTask::delay) this does not block the task, the wake-up is driven by a
+/// one-shot [`Timer`] so it can be used within [`block_on_future`](crate::task::block_on_future), e.g. raced against
+/// other futures.
+///
+/// # Panics
+///
+/// When polled, if the timer cannot be created or started.
+pub fn sleep(duration: Duration) -> Sleep {
+    Sleep {
+        start: scheduler::get_tick_count(),
+        duration,
+        state: Arc::new(SleepState {
+            elapsed: AtomicBool::new(false),
+            waker: AtomicWaker::new(),
+        }),
+        timer: None,
+        timer_started: false,
+    }
+}
+
+/// State shared between a [`Sleep`] and its timer callback.
+#[derive(Debug)]
+struct SleepState {
+    elapsed: AtomicBool,
+    waker: AtomicWaker,
+}
+
+/// Future returned by [`sleep`].
+///
+/// Dropping it deletes the backing timer.
+#[must_use = "futures do nothing unless polled"]
+pub struct Sleep {
+    /// Tick count at which the sleep started.
+    start: TickType_t,
+    duration: Duration,
+    state: Arc<SleepState>,
+    /// Created on first poll with the then remaining duration.
+    timer: Option<Timer<BoxedTimerCallback>>,
+    /// Whether the command starting the timer was sent, this fails while the timer command queue is full.
+    timer_started: bool,
+}
+
+impl Sleep {
+    /// Returns `true` if the full duration has elapsed.
+    fn is_elapsed(&self) -> bool {
+        self.state.elapsed.load(Ordering::Acquire)
+            || Duration::elapsed_since(self.start).ticks() >= self.duration.ticks()
+    }
+}
+
+impl Future for Sleep {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+
+        if this.is_elapsed() {
+            return Poll::Ready(());
+        }
+
+        this.state.waker.register(cx.waker());
+
+        if !this.timer_started {
+            let remaining = Duration::from_ticks(
+                this.duration.ticks() - Duration::elapsed_since(this.start).ticks(),
+            );
+            let started = match &this.timer {
+                // Changing the period starts the timer with the now remaining duration.
+                Some(timer) => timer.handle().change_period(remaining),
+                None => {
+                    let state = Arc::clone(&this.state);
+                    let callback: BoxedTimerCallback = Box::new(move |_| {
+                        state.elapsed.store(true, Ordering::Release);
+                        state.waker.wake();
+                    });
+                    let timer = Timer::once(None, remaining, callback)
+                        .expect("failed to create sleep timer");
+                    this.timer.insert(timer).handle().start()
+                }
+            };
+
+            match started {
+                Ok(()) => this.timer_started = true,
+                // The timer command queue is full, retry on the next poll.
+                Err(_) => cx.waker().wake_by_ref(),
+            }
+        }
+
+        // The timer may have expired between the first check and registering the waker.
+        if this.is_elapsed() {
+            Poll::Ready(())
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+impl Debug for Sleep {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Sleep")
+            .field("start", &self.start)
+            .field("duration", &self.duration)
+            .field("state", &self.state)
+            .finish_non_exhaustive()
+    }
+}
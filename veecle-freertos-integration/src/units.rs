@@ -1,5 +1,16 @@
 //! Expose time units type and implementation utilities.
-use veecle_freertos_sys::bindings::{TickType_t, portMAX_DELAY, portTICK_PERIOD_MS};
+use core::cmp::Ordering;
+use core::ops::Add;
+
+use veecle_freertos_sys::bindings::{
+    TickType_t, configTICK_RATE_HZ, pdMS_TO_TICKS, pdTICKS_TO_MS, portMAX_DELAY,
+    xTaskGetTickCountFromISR,
+};
+
+/// Returns the tick rate in Hz, as configured by `configTICK_RATE_HZ`.
+pub const fn tick_rate_hz() -> u32 {
+    configTICK_RATE_HZ as u32
+}
 
 /// A FreeRTOS duration, internally represented as ticks.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
@@ -15,9 +26,54 @@ impl Duration {
 
     /// Creates a new `Duration` from the specified number of milliseconds.
     ///
-    /// Because the duration is internally represented in ticks this may not result in an exact duration.
+    /// Because the duration is internally represented in ticks this may not result in an exact duration. The
+    /// conversion matches FreeRTOS' `pdMS_TO_TICKS`, except that a non-zero number of milliseconds results in at least
+    /// one tick, so a blocking call never silently turns non-blocking.
     pub fn from_ms(milliseconds: TickType_t) -> Self {
-        Self::from_ticks(milliseconds / portTICK_PERIOD_MS())
+        let ticks = pdMS_TO_TICKS(milliseconds);
+        if ticks == 0 && milliseconds != 0 {
+            return Self::eps();
+        }
+        Self::from_ticks(ticks)
+    }
+
+    /// Like [`from_ms`](Self::from_ms), but usable in `const` contexts, e.g. for `const TIMEOUT: Duration`.
+    ///
+    /// Computes the same number of ticks as `from_ms`, based on the compile-time `configTICK_RATE_HZ`. Tick counts that
+    /// don't fit into `TickType_t` are truncated.
+    pub const fn from_ms_const(milliseconds: TickType_t) -> Self {
+        // Same computation as `pdMS_TO_TICKS`, widened to avoid overflowing the intermediate product. `TickType_t` is
+        // already `u64` on some ports.
+        #[allow(clippy::unnecessary_cast)]
+        let ticks = (milliseconds as u64 * configTICK_RATE_HZ as u64 / 1000) as TickType_t;
+        if ticks == 0 && milliseconds != 0 {
+            return Self { ticks: 1 };
+        }
+        Self { ticks }
+    }
+
+    /// Creates a new `Duration` from the specified number of seconds.
+    ///
+    /// Durations that don't fit into `TickType_t` saturate to the longest finite duration, one tick less than
+    /// [`infinite`](Self::infinite).
+    pub fn from_secs(seconds: u32) -> Self {
+        Self::from_secs_saturating(u64::from(seconds))
+    }
+
+    /// Creates a new `Duration` from the specified number of minutes.
+    ///
+    /// Saturates like [`from_secs`](Self::from_secs).
+    pub fn from_mins(minutes: u32) -> Self {
+        Self::from_secs_saturating(u64::from(minutes) * 60)
+    }
+
+    /// Converts `seconds` to ticks with a 64-bit intermediate, saturating to the longest finite duration.
+    fn from_secs_saturating(seconds: u64) -> Self {
+        let max_finite = portMAX_DELAY() - 1;
+        let ticks = seconds.saturating_mul(u64::from(tick_rate_hz()));
+        Self::from_ticks(
+            TickType_t::try_from(ticks).map_or(max_finite, |ticks| ticks.min(max_finite)),
+        )
     }
 
     /// Creates a new `Duration` from the specified number of ticks.
@@ -41,9 +97,34 @@ impl Duration {
         Self::from_ticks(1)
     }
 
+    /// Returns the time elapsed between the tick counts `earlier` and `later`.
+    ///
+    /// The tick count wraps around, `later` is assumed to be at most one full tick counter range after `earlier`.
+    pub fn between(earlier: TickType_t, later: TickType_t) -> Self {
+        Self::from_ticks(later.wrapping_sub(earlier))
+    }
+
+    /// Returns the time elapsed since the tick count `start`, as returned by
+    /// [`scheduler::get_tick_count`](crate::scheduler::get_tick_count).
+    ///
+    /// Handles the tick count wrapping around like [`between`](Self::between).
+    pub fn elapsed_since(start: TickType_t) -> Self {
+        Self::between(start, crate::scheduler::get_tick_count())
+    }
+
     /// Returns the number of milliseconds contained in this `Duration`.
+    ///
+    /// The conversion matches FreeRTOS' `pdTICKS_TO_MS`.
     pub fn ms(&self) -> TickType_t {
-        self.ticks * portTICK_PERIOD_MS()
+        pdTICKS_TO_MS(self.ticks)
+    }
+
+    /// Returns the number of milliseconds contained in this `Duration` as a float, without the truncation of
+    /// [`ms`](Self::ms).
+    ///
+    /// Intended for logging and rate computations, large durations lose precision.
+    pub fn as_millis_f32(&self) -> f32 {
+        self.ticks as f32 * 1000.0 / configTICK_RATE_HZ as f32
     }
 
     /// Returns the number of ticks contained in this `Duration`.
@@ -51,3 +132,113 @@ impl Duration {
         self.ticks
     }
 }
+
+impl PartialEq<TickType_t> for Duration {
+    fn eq(&self, ticks: &TickType_t) -> bool {
+        self.ticks == *ticks
+    }
+}
+
+impl PartialOrd<TickType_t> for Duration {
+    fn partial_cmp(&self, ticks: &TickType_t) -> Option<Ordering> {
+        self.ticks.partial_cmp(ticks)
+    }
+}
+
+/// A point in time, in ticks since the scheduler was started.
+///
+/// A monotonic clock like `std::time::Instant`, based on
+/// [`scheduler::get_tick_count`](crate::scheduler::get_tick_count). Use [`InstantFromIsr`] in ISR contexts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Instant {
+    ticks: TickType_t,
+}
+
+impl Instant {
+    /// Returns the current point in time.
+    pub fn now() -> Self {
+        Self::from_ticks(crate::scheduler::get_tick_count())
+    }
+
+    /// Creates an instant at the tick count `ticks`.
+    pub fn from_ticks(ticks: TickType_t) -> Self {
+        Self { ticks }
+    }
+
+    /// Returns the time elapsed since this instant was captured.
+    pub fn elapsed(&self) -> Duration {
+        Self::now().duration_since(*self)
+    }
+
+    /// Returns the time elapsed from `earlier` to `self`.
+    ///
+    /// The tick count wraps around, durations longer than the tick counter's range are not representable.
+    pub fn duration_since(&self, earlier: Instant) -> Duration {
+        Duration::between(earlier.ticks, self.ticks)
+    }
+
+    /// Returns the time left until this instant, zero if it has passed.
+    ///
+    /// Useful for deadlines, where repeated waits should share one overall timeout. Instants more than half the tick
+    /// counter's range in the future are considered passed, due to the tick count wrapping around.
+    pub fn remaining(&self) -> Duration {
+        let remaining = self.duration_since(Self::now());
+        if remaining.ticks() > TickType_t::MAX / 2 {
+            Duration::zero()
+        } else {
+            remaining
+        }
+    }
+
+    /// Returns the number of ticks since the scheduler was started at this instant.
+    pub fn ticks(&self) -> TickType_t {
+        self.ticks
+    }
+}
+
+impl Add<Duration> for Instant {
+    type Output = Instant;
+
+    /// Returns the instant `duration` after `self`, wrapping around like the tick count.
+    fn add(self, duration: Duration) -> Instant {
+        Self::from_ticks(self.ticks.wrapping_add(duration.ticks()))
+    }
+}
+
+/// A point in time, in ticks since the scheduler was started, for use in ISR contexts.
+///
+/// Unlike [`scheduler::get_tick_count`](crate::scheduler::get_tick_count), the tick count is read with the ISR-safe
+/// `xTaskGetTickCountFromISR`, so this can be used to measure durations inside interrupt handlers. It may be used in
+/// task contexts as well.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct InstantFromIsr {
+    ticks: TickType_t,
+}
+
+impl InstantFromIsr {
+    /// Returns the current point in time.
+    pub fn now() -> Self {
+        // SAFETY:
+        // No requirements on the caller, this function is safe to call from ISR and non-ISR contexts.
+        let ticks = unsafe { xTaskGetTickCountFromISR() };
+
+        Self { ticks }
+    }
+
+    /// Returns the time elapsed since this instant was captured.
+    pub fn elapsed(&self) -> Duration {
+        Self::now().duration_since(*self)
+    }
+
+    /// Returns the time elapsed from `earlier` to `self`.
+    ///
+    /// The tick count wraps around, durations longer than the tick counter's range are not representable.
+    pub fn duration_since(&self, earlier: InstantFromIsr) -> Duration {
+        Duration::between(earlier.ticks, self.ticks)
+    }
+
+    /// Returns the number of ticks since the scheduler was started at this instant.
+    pub fn ticks(&self) -> TickType_t {
+        self.ticks
+    }
+}
@@ -0,0 +1,62 @@
+//! A set of wakers shared between multiple tasks.
+
+use alloc::vec::Vec;
+use core::cell::UnsafeCell;
+use core::fmt::{Debug, Formatter};
+use core::task::Waker;
+
+use crate::scheduler::with_scheduler_suspended;
+
+/// Holds the wakers of any number of pending futures, unlike [`atomic_waker::AtomicWaker`] which only holds one.
+///
+/// Access is synchronized by suspending the scheduler, so the set must not be used from ISR contexts.
+pub(crate) struct WakerSet {
+    wakers: UnsafeCell<Vec<Waker>>,
+}
+
+// SAFETY: All accesses to `wakers` happen with the scheduler suspended, so no two tasks can access it concurrently.
+unsafe impl Send for WakerSet {}
+
+// SAFETY: All accesses to `wakers` happen with the scheduler suspended, so no two tasks can access it concurrently.
+unsafe impl Sync for WakerSet {}
+
+impl WakerSet {
+    /// Creates an empty `WakerSet`.
+    pub(crate) const fn new() -> Self {
+        Self {
+            wakers: UnsafeCell::new(Vec::new()),
+        }
+    }
+
+    /// Registers `waker` to be woken by the next call to [`wake`](Self::wake).
+    ///
+    /// Registering a waker that would wake the same task as an already registered waker is a no-op.
+    pub(crate) fn register(&self, waker: &Waker) {
+        with_scheduler_suspended(|| {
+            // SAFETY: The scheduler is suspended, so this is the only access to `wakers`.
+            let wakers = unsafe { &mut *self.wakers.get() };
+            if !wakers.iter().any(|registered| registered.will_wake(waker)) {
+                wakers.push(waker.clone());
+            }
+        });
+    }
+
+    /// Wakes and removes all registered wakers.
+    pub(crate) fn wake(&self) {
+        let wakers = with_scheduler_suspended(|| {
+            // SAFETY: The scheduler is suspended, so this is the only access to `wakers`.
+            core::mem::take(unsafe { &mut *self.wakers.get() })
+        });
+
+        // Waking happens after resuming the scheduler, as wakers may call into arbitrary FreeRTOS APIs.
+        for waker in wakers {
+            waker.wake();
+        }
+    }
+}
+
+impl Debug for WakerSet {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("WakerSet").finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,32 @@
+#![expect(missing_docs)]
+
+pub mod common;
+
+#[common::apply(common::test)]
+fn allocator_realloc() {
+    let mut vec = Vec::new();
+    for value in 0..1000_u32 {
+        vec.push(value);
+    }
+    assert!(vec.iter().copied().eq(0..1000));
+
+    vec.truncate(10);
+    vec.shrink_to_fit();
+    assert!(vec.iter().copied().eq(0..10));
+
+    #[repr(align(4096))]
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct Aligned(u32);
+
+    let mut aligned = vec![Aligned(0)];
+    for value in 1..10 {
+        aligned.push(Aligned(value));
+    }
+    assert!(aligned.as_ptr().is_aligned());
+    assert!(aligned.iter().map(|aligned| aligned.0).eq(0..10));
+
+    aligned.truncate(2);
+    aligned.shrink_to_fit();
+    assert!(aligned.as_ptr().is_aligned());
+    assert_eq!(aligned, [Aligned(0), Aligned(1)]);
+}
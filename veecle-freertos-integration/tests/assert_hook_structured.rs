@@ -0,0 +1,41 @@
+#![expect(missing_docs)]
+
+use veecle_freertos_integration::Task;
+use veecle_freertos_integration::hooks::AssertInfo;
+
+pub mod common;
+
+// `vAssertCalled` is an `extern "C"` function.
+// Because Rust cannot unwind panics in `extern "C"` functions, we need to redirect the program flow out of the panic
+// hook.
+
+#[common::apply(common::test)]
+fn assert_hook_structured() {
+    Task::new()
+        .start(|_| {
+            veecle_freertos_integration::hooks::set_structured_assert_panic(true);
+
+            std::panic::set_hook(Box::new(|info| {
+                let assert_info = info
+                    .payload()
+                    .downcast_ref::<AssertInfo>()
+                    .expect("payload should be an `AssertInfo`");
+                assert!(
+                    assert_info
+                        .file
+                        .contains("/veecle-freertos-sys/macro-shim.h")
+                );
+                assert_eq!(assert_info.line, 33);
+
+                common::end_scheduler();
+            }));
+
+            // SAFETY: No safety requirements.
+            unsafe {
+                veecle_freertos_sys::bindings::shim_configASSERT(0);
+            }
+        })
+        .unwrap();
+
+    veecle_freertos_integration::scheduler::start_scheduler();
+}
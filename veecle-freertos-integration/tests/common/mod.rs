@@ -1,5 +1,6 @@
 pub use macro_rules_attribute::apply;
-use veecle_freertos_integration::{FreeRtosAllocator, Task};
+use veecle_freertos_integration::{FreeRtosAllocator, Task, TaskPriority};
+use veecle_freertos_sys::bindings::StackType_t;
 
 #[macro_export]
 /// An alternative to the `libtest::test` macro that uses `libtest-mimic` to run a single single-threaded test.
@@ -18,6 +19,9 @@ use veecle_freertos_integration::{FreeRtosAllocator, Task};
 /// to avoid `libtest` being included.
 ///
 /// Should be applied as `#[common::apply(common::test)]`.
+///
+/// Tests depending on the FreeRTOS configuration can add `#[requires(<cfg predicate>)]` below, the test is then
+/// reported as ignored instead of passing if the predicate doesn't hold.
 macro_rules! test {
     (fn $name:ident() $body:block) => {
         fn main() {
@@ -31,6 +35,25 @@ macro_rules! test {
             .exit()
         }
     };
+    (#[requires($predicate:meta)] fn $name:ident() $body:block) => {
+        fn main() {
+            libtest_mimic::run(
+                &libtest_mimic::Arguments {
+                    test_threads: Some(1),
+                    ..libtest_mimic::Arguments::from_args()
+                },
+                vec![
+                    libtest_mimic::Trial::test(stringify!($name), || {
+                        #[cfg($predicate)]
+                        $body
+                        Ok(())
+                    })
+                    .with_ignored_flag(!cfg!($predicate)),
+                ],
+            )
+            .exit()
+        }
+    };
 }
 
 pub use crate::test;
@@ -71,3 +94,18 @@ where
 {
     Task::new().start(func).unwrap()
 }
+
+/// Starts a task with the given priority and stack size (in words) in tests without error handling.
+///
+/// [`start_task`] uses the [`Task::new`] defaults instead.
+pub fn start_task_with<F>(priority: TaskPriority, stack_size: StackType_t, func: F) -> Task
+where
+    F: FnOnce(Task),
+    F: Send + 'static,
+{
+    Task::new()
+        .priority(priority)
+        .stack_size(stack_size)
+        .start(func)
+        .unwrap()
+}
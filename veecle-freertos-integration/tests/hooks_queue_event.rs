@@ -0,0 +1,41 @@
+#![expect(missing_docs)]
+
+use std::sync::atomic::{AtomicPtr, AtomicUsize, Ordering};
+
+use veecle_freertos_integration::hooks::{QueueEvent, set_on_queue_event};
+use veecle_freertos_integration::{Duration, Queue};
+use veecle_freertos_sys::bindings::QueueHandle_t;
+
+pub mod common;
+
+static OBSERVED_QUEUE: AtomicPtr<core::ffi::c_void> = AtomicPtr::new(core::ptr::null_mut());
+static SENDS: AtomicUsize = AtomicUsize::new(0);
+static RECEIVES: AtomicUsize = AtomicUsize::new(0);
+
+fn on_queue_event(queue: QueueHandle_t, event: QueueEvent) {
+    // The hook observes all queues, including the ones used internally by FreeRTOS.
+    if queue.cast() != OBSERVED_QUEUE.load(Ordering::Relaxed) {
+        return;
+    }
+
+    match event {
+        QueueEvent::Send => SENDS.fetch_add(1, Ordering::Relaxed),
+        QueueEvent::Receive => RECEIVES.fetch_add(1, Ordering::Relaxed),
+    };
+}
+
+#[common::apply(common::test)]
+fn hooks_queue_event() {
+    let queue: Queue<u8> = Queue::new(5).expect("queue to be created");
+    OBSERVED_QUEUE.store(queue.raw_handle().cast(), Ordering::Relaxed);
+    set_on_queue_event(on_queue_event);
+
+    common::run_freertos_test(move || {
+        assert_eq!(queue.send(1, Duration::zero()), Ok(()));
+        assert_eq!(queue.send(2, Duration::zero()), Ok(()));
+        assert_eq!(queue.receive(Duration::zero()), Ok(1));
+
+        assert_eq!(SENDS.load(Ordering::Relaxed), 2);
+        assert_eq!(RECEIVES.load(Ordering::Relaxed), 1);
+    });
+}
@@ -0,0 +1,23 @@
+#![expect(missing_docs)]
+
+use veecle_freertos_integration::{Duration, InterruptContext, Queue};
+use veecle_freertos_sys::bindings::pdFALSE;
+
+pub mod common;
+
+const CONTEXT: InterruptContext = InterruptContext::new();
+
+#[common::apply(common::test)]
+fn isr_const() {
+    let queue: Queue<u8> = Queue::new(1).unwrap();
+
+    common::run_freertos_test(move || {
+        let mut context = CONTEXT;
+        assert_eq!(context.higher_priority_task_woken(), pdFALSE());
+
+        queue.send_from_isr(&mut context, 1).unwrap();
+        drop(context);
+
+        assert_eq!(queue.receive(Duration::zero()).unwrap(), 1);
+    });
+}
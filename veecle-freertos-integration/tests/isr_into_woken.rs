@@ -0,0 +1,45 @@
+#![expect(missing_docs)]
+
+use core::sync::atomic::{AtomicBool, Ordering};
+
+use veecle_freertos_integration::scheduler::with_critical_section;
+use veecle_freertos_integration::{
+    CurrentTask, Duration, InterruptContext, Task, TaskNotification, TaskPriority,
+};
+use veecle_freertos_sys::bindings::{pdTRUE, portYIELD_FROM_ISR};
+
+pub mod common;
+
+static WOKEN_RAN: AtomicBool = AtomicBool::new(false);
+
+#[common::apply(common::test)]
+fn isr_into_woken() {
+    let waiter = Task::new()
+        .priority(TaskPriority(2))
+        .start(|task| {
+            task.wait_for_notification(0, 0, Duration::infinite())
+                .unwrap();
+            WOKEN_RAN.store(true, Ordering::SeqCst);
+
+            CurrentTask::suspend();
+        })
+        .unwrap();
+
+    common::run_freertos_test(move || {
+        // The critical section keeps the tick interrupt from switching to the woken task.
+        let woken = with_critical_section(|| {
+            let mut interrupt_context = InterruptContext::new();
+            waiter
+                .notify_from_isr(&mut interrupt_context, TaskNotification::NoAction)
+                .unwrap();
+
+            let woken = interrupt_context.into_woken();
+            assert!(!WOKEN_RAN.load(Ordering::SeqCst));
+            woken
+        });
+        assert_eq!(woken, pdTRUE());
+
+        portYIELD_FROM_ISR(woken);
+        assert!(WOKEN_RAN.load(Ordering::SeqCst));
+    });
+}
@@ -0,0 +1,49 @@
+#![expect(missing_docs)]
+
+use std::sync::atomic::{AtomicU32, Ordering};
+
+use veecle_freertos_integration::{
+    CurrentTask, Duration, InterruptContext, Queue, TaskNotification, TaskPriority,
+};
+use veecle_freertos_sys::bindings::pdTRUE;
+
+pub mod common;
+
+#[common::apply(common::test)]
+fn isr_scope() {
+    static WOKEN_TASKS: AtomicU32 = AtomicU32::new(0);
+
+    let queue: Queue<u8> = Queue::new(1).unwrap();
+
+    common::run_freertos_test(move || {
+        let receiver = queue.clone();
+        common::start_task_with(TaskPriority(2), 1024, move |_| {
+            receiver.receive(Duration::infinite()).unwrap();
+            WOKEN_TASKS.fetch_add(1, Ordering::Release);
+            CurrentTask::suspend();
+        });
+        let waiter = common::start_task_with(TaskPriority(2), 1024, |task| {
+            task.wait_for_notification(0, 0, Duration::infinite())
+                .unwrap();
+            WOKEN_TASKS.fetch_add(1, Ordering::Release);
+            CurrentTask::suspend();
+        });
+
+        InterruptContext::scope(|context| {
+            queue.send_from_isr(context, 1).unwrap();
+
+            let mut nested = InterruptContext::new();
+            waiter
+                .notify_from_isr(&mut nested, TaskNotification::NoAction)
+                .unwrap();
+            context.merge(nested);
+
+            assert_eq!(context.higher_priority_task_woken(), pdTRUE());
+            // Nothing yielded yet.
+            assert_eq!(WOKEN_TASKS.load(Ordering::Acquire), 0);
+        });
+
+        // The single yield at the end of the scope ran both woken tasks.
+        assert_eq!(WOKEN_TASKS.load(Ordering::Acquire), 2);
+    });
+}
@@ -0,0 +1,32 @@
+#![expect(missing_docs)]
+
+use veecle_freertos_integration::scheduler::with_critical_section;
+use veecle_freertos_integration::{CurrentTask, Duration, InterruptContext, Queue, TaskPriority};
+
+pub mod common;
+
+#[common::apply(common::test)]
+fn isr_should_yield() {
+    let queue: Queue<u8> = Queue::new(2).unwrap();
+
+    common::run_freertos_test(move || {
+        let receiver = queue.clone();
+        common::start_task_with(TaskPriority(2), 1024, move |_| {
+            receiver.receive(Duration::infinite()).unwrap();
+            CurrentTask::suspend();
+        });
+
+        // The critical section keeps the tick interrupt from switching to the woken task.
+        with_critical_section(|| {
+            let mut interrupt_context = InterruptContext::new();
+            assert!(!interrupt_context.should_yield());
+
+            queue.send_from_isr(&mut interrupt_context, 1).unwrap();
+            assert!(interrupt_context.should_yield());
+
+            // Querying doesn't consume the flag.
+            assert!(interrupt_context.should_yield());
+            interrupt_context.set_yield_on_drop(false);
+        });
+    });
+}
@@ -0,0 +1,35 @@
+#![expect(missing_docs)]
+
+use veecle_freertos_integration::{
+    CurrentTask, Duration, InterruptContext, Queue, Task, TaskNotification, TaskPriority,
+};
+use veecle_freertos_sys::bindings::{pdFALSE, pdTRUE};
+
+pub mod common;
+
+#[common::apply(common::test)]
+fn isr_woken_on_failure() {
+    let queue: Queue<u8> = Queue::new(1).unwrap();
+    assert_eq!(queue.send(0, Duration::zero()), Ok(()));
+
+    common::run_freertos_test(move || {
+        let mut interrupt_context = InterruptContext::new();
+        interrupt_context.set_yield_on_drop(false);
+
+        assert_eq!(queue.send_from_isr(&mut interrupt_context, 1), Err(1));
+        assert_eq!(interrupt_context.higher_priority_task_woken(), pdFALSE());
+
+        // Successful operations still set the flag.
+        let waiter = Task::new()
+            .priority(TaskPriority(2))
+            .start(|task| {
+                let _ = task.wait_for_notification(0, 0, Duration::infinite());
+                CurrentTask::suspend();
+            })
+            .unwrap();
+        waiter
+            .notify_from_isr(&mut interrupt_context, TaskNotification::NoAction)
+            .unwrap();
+        assert_eq!(interrupt_context.higher_priority_task_woken(), pdTRUE());
+    });
+}
@@ -0,0 +1,56 @@
+#![expect(missing_docs)]
+
+use veecle_freertos_integration::logger::{LogRecord, LoggerTask};
+use veecle_freertos_integration::{CurrentTask, Duration, Queue, Task, TaskPriority, freertos_log};
+
+pub mod common;
+
+#[common::apply(common::test)]
+fn logger() {
+    common::run_freertos_test(|| {
+        let emitted: Queue<LogRecord> = Queue::new(8).unwrap();
+
+        let sink_queue = emitted.clone();
+        let logger = LoggerTask::new(8, move |record: &LogRecord| {
+            sink_queue.send(record.clone(), Duration::zero()).unwrap();
+        })
+        .priority(TaskPriority(2))
+        .start()
+        .unwrap();
+
+        let start_producer = |name, prefix: &'static str| {
+            let logger = logger.clone();
+            Task::new()
+                .name(name)
+                .start(move |_| {
+                    for i in 0..3 {
+                        assert!(freertos_log!(logger, "{prefix}{i}"));
+                        CurrentTask::delay(Duration::eps());
+                    }
+                    CurrentTask::suspend();
+                })
+                .unwrap()
+        };
+        let first = start_producer(c"first", "a");
+        let second = start_producer(c"second", "b");
+
+        let records: Vec<LogRecord> = (0..6)
+            .map(|_| emitted.receive(Duration::from_ms(1000)).unwrap())
+            .collect();
+
+        for (producer, prefix) in [(&first, "a"), (&second, "b")] {
+            let messages: Vec<&str> = records
+                .iter()
+                .filter(|record| record.task().map(Task::raw_handle) == Some(producer.raw_handle()))
+                .map(LogRecord::message)
+                .collect();
+            assert_eq!(messages, [0, 1, 2].map(|i| format!("{prefix}{i}")));
+        }
+        assert!(
+            records
+                .windows(2)
+                .all(|pair| pair[0].tick() <= pair[1].tick())
+        );
+        assert!(records[0].to_string().contains(": "));
+    });
+}
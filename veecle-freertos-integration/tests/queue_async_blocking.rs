@@ -11,10 +11,10 @@ fn queue_async() {
     let queue = Queue::new(1).expect("queue to be created");
 
     let mut receiver = BlockingToAsyncQueueTaskBuilder::new(c"receiver", queue.clone(), 1)
-        .create()
+        .create_detached()
         .unwrap();
     let mut sender = AsyncToBlockingQueueTaskBuilder::new(c"sender", queue, 1)
-        .create()
+        .create_detached()
         .unwrap();
 
     Task::new()
@@ -0,0 +1,28 @@
+#![expect(missing_docs)]
+
+use futures::FutureExt;
+use veecle_freertos_integration::channel;
+
+pub mod common;
+
+#[common::apply(common::test)]
+fn queue_async_closed() {
+    let (mut sender, mut receiver) = channel::<()>(1).expect("queue to be created");
+
+    common::run_freertos_test(move || {
+        assert_eq!(sender.send(()).now_or_never(), Some(Ok(())));
+        assert!(!receiver.is_closed());
+
+        drop(sender);
+        assert!(receiver.is_closed());
+
+        // Items sent before closing are still delivered.
+        assert_eq!(receiver.receive().now_or_never(), Some(Some(())));
+        assert_eq!(receiver.receive().now_or_never(), Some(None));
+
+        let (mut sender, receiver) = channel::<()>(1).expect("queue to be created");
+        drop(receiver);
+        assert!(sender.is_closed());
+        assert_eq!(sender.send(()).now_or_never(), Some(Err(())));
+    })
+}
@@ -0,0 +1,44 @@
+#![expect(missing_docs)]
+
+pub mod common;
+
+#[common::apply(common::test)]
+fn queue_async_drop_drains() {
+    #[cfg(panic = "unwind")]
+    {
+        use std::panic::{AssertUnwindSafe, catch_unwind};
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        use futures::FutureExt;
+        use veecle_freertos_integration::channel;
+
+        static DROPPED: AtomicUsize = AtomicUsize::new(0);
+
+        struct Item {
+            panics: bool,
+        }
+
+        impl Drop for Item {
+            fn drop(&mut self) {
+                DROPPED.fetch_add(1, Ordering::AcqRel);
+                if self.panics {
+                    panic!("item drop");
+                }
+            }
+        }
+
+        common::run_freertos_test(|| {
+            let (mut sender, receiver) = channel::<Item>(3).expect("queue to be created");
+            for panics in [false, true, false] {
+                assert!(sender.send(Item { panics }).now_or_never().unwrap().is_ok());
+            }
+
+            drop(receiver);
+            let payload = catch_unwind(AssertUnwindSafe(|| drop(sender))).unwrap_err();
+
+            // The panic of the second item is resumed after the third item was dropped as well.
+            assert_eq!(payload.downcast_ref::<&str>(), Some(&"item drop"));
+            assert_eq!(DROPPED.load(Ordering::Acquire), 3);
+        });
+    }
+}
@@ -1,7 +1,7 @@
 #![expect(missing_docs)]
 
 use futures::FutureExt;
-use veecle_freertos_integration::{CurrentTask, Duration, Task, TaskPriority, channel};
+use veecle_freertos_integration::{CurrentTask, Duration, TaskPriority, channel};
 
 pub mod common;
 
@@ -9,19 +9,20 @@ pub mod common;
 fn queue_async_messages_waiting() {
     let (mut sender, mut receiver) = channel::<()>(1).expect("queue to be created");
 
-    Task::new()
-        .priority(TaskPriority(2))
-        .start(move |_| {
-            assert_eq!(sender.messages_waiting(), 0);
-            sender.send(()).now_or_never().expect("message to be sent");
-            assert_eq!(sender.messages_waiting(), 1);
+    common::start_task_with(TaskPriority(2), 1024, move |_| {
+        assert_eq!(sender.messages_waiting(), 0);
+        sender
+            .send(())
+            .now_or_never()
+            .expect("message to be sent")
+            .expect("receiver to be alive");
+        assert_eq!(sender.messages_waiting(), 1);
 
-            CurrentTask::delay(Duration::infinite());
-        })
-        .unwrap();
+        CurrentTask::delay(Duration::infinite());
+    });
 
     common::run_freertos_test(move || {
-        assert_eq!(receiver.receive().now_or_never(), Some(()));
+        assert_eq!(receiver.receive().now_or_never(), Some(Some(())));
         assert_eq!(receiver.messages_waiting(), 0);
     });
 }
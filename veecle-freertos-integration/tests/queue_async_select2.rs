@@ -0,0 +1,22 @@
+#![expect(missing_docs)]
+
+use futures::FutureExt;
+use veecle_freertos_integration::{Either, channel, select2};
+
+pub mod common;
+
+#[common::apply(common::test)]
+fn queue_async_select2() {
+    let (_first_sender, mut first) = channel::<u8>(1).expect("queue to be created");
+    let (mut second_sender, mut second) = channel::<u16>(1).expect("queue to be created");
+
+    common::run_freertos_test(move || {
+        assert_eq!(select2(&mut first, &mut second).now_or_never(), None);
+
+        assert_eq!(second_sender.send(2).now_or_never(), Some(Ok(())));
+        assert_eq!(
+            select2(&mut first, &mut second).now_or_never(),
+            Some(Some(Either::B(2)))
+        );
+    });
+}
@@ -0,0 +1,60 @@
+#![expect(missing_docs)]
+
+use futures::FutureExt;
+use veecle_freertos_integration::{channel, select_biased};
+
+pub mod common;
+
+#[common::apply(common::test)]
+fn queue_async_select_biased() {
+    let (mut shutdown_sender, mut shutdown) = channel::<()>(1).expect("queue to be created");
+    let (mut data_sender, mut data) = channel::<u8>(1).expect("queue to be created");
+
+    common::run_freertos_test(move || {
+        assert_eq!(data_sender.send(1).now_or_never(), Some(Ok(())));
+        assert_eq!(shutdown_sender.send(()).now_or_never(), Some(Ok(())));
+
+        let stopped = async {
+            select_biased! {
+                _ = shutdown.receive() => true,
+                _ = data.receive() => false,
+            }
+        };
+        assert_eq!(stopped.now_or_never(), Some(true));
+
+        // The data branch wasn't taken, so its item is still waiting.
+        assert_eq!(data.messages_waiting(), 1);
+
+        // A closed data channel disables its branch instead of completing the select.
+        drop(data_sender);
+        assert_eq!(data.receive().now_or_never(), Some(Some(1)));
+        assert_eq!(shutdown_sender.send(()).now_or_never(), Some(Ok(())));
+
+        let stopped = async {
+            select_biased! {
+                Some(_) = data.receive() => false,
+                _ = shutdown.receive() => true,
+            }
+        };
+        assert_eq!(stopped.now_or_never(), Some(true));
+
+        // Bodies run in the surrounding scope: they can `break` its loop, share mutable state and use the receivers
+        // the futures borrowed.
+        let (mut data_sender, mut data) = channel::<u8>(2).expect("queue to be created");
+        assert_eq!(data_sender.send(1).now_or_never(), Some(Ok(())));
+        assert_eq!(data_sender.send(2).now_or_never(), Some(Ok(())));
+        assert_eq!(shutdown_sender.send(()).now_or_never(), Some(Ok(())));
+
+        let mut received = Vec::new();
+        let drained = async {
+            loop {
+                select_biased! {
+                    Some(value) = data.receive() => received.push((value, data.messages_waiting())),
+                    _ = shutdown.receive() => break,
+                }
+            }
+        };
+        assert_eq!(drained.now_or_never(), Some(()));
+        assert_eq!(received, [(1, 1), (2, 0)]);
+    });
+}
@@ -7,10 +7,10 @@ pub mod common;
 
 #[common::apply(common::test)]
 fn queue_async_send_exceed_max_capacity() {
-    let (mut sender, _) = channel::<()>(1).expect("queue to be created");
+    let (mut sender, _receiver) = channel::<()>(1).expect("queue to be created");
 
     common::run_freertos_test(move || {
-        assert_eq!(sender.send(()).now_or_never(), Some(()));
+        assert_eq!(sender.send(()).now_or_never(), Some(Ok(())));
         assert_eq!(sender.send(()).now_or_never(), None);
     })
 }
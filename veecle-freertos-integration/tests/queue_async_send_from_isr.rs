@@ -24,6 +24,6 @@ fn queue_async_send_from_isr() {
         .unwrap();
 
     common::run_freertos_test(move || {
-        assert_eq!(receiver.receive().now_or_never(), Some(()));
+        assert_eq!(receiver.receive().now_or_never(), Some(Some(())));
     });
 }
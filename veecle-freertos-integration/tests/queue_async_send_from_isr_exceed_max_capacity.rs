@@ -6,7 +6,7 @@ pub mod common;
 
 #[common::apply(common::test)]
 fn queue_async_send_from_isr_exceed_max_capacity() {
-    let (mut sender, _) = channel::<()>(1).expect("queue to be created");
+    let (mut sender, _receiver) = channel::<()>(1).expect("queue to be created");
 
     common::run_freertos_test(move || {
         let mut interrupt_context = InterruptContext::default();
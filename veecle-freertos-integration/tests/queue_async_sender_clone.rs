@@ -0,0 +1,45 @@
+#![expect(missing_docs)]
+
+use futures::FutureExt;
+use veecle_freertos_integration::{CurrentTask, Duration, TaskPriority, channel};
+
+pub mod common;
+
+#[common::apply(common::test)]
+fn queue_async_sender_clone() {
+    let (mut first, mut receiver) = channel::<u8>(2).expect("queue to be created");
+    let mut second = first.clone();
+
+    common::start_task_with(TaskPriority(2), 1024, move |_| {
+        assert_eq!(first.send(1).now_or_never(), Some(Ok(())));
+
+        CurrentTask::delay(Duration::infinite());
+    });
+
+    common::start_task_with(TaskPriority(2), 1024, move |_| {
+        assert_eq!(second.send(2).now_or_never(), Some(Ok(())));
+        drop(second);
+
+        CurrentTask::delay(Duration::infinite());
+    });
+
+    common::run_freertos_test(move || {
+        let mut received = [
+            receiver
+                .receive()
+                .now_or_never()
+                .flatten()
+                .expect("first item"),
+            receiver
+                .receive()
+                .now_or_never()
+                .flatten()
+                .expect("second item"),
+        ];
+        received.sort();
+        assert_eq!(received, [1, 2]);
+
+        // One of the clones is still alive, so the channel isn't closed.
+        assert!(!receiver.is_closed());
+    });
+}
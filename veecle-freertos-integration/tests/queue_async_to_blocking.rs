@@ -1,9 +1,7 @@
 #![expect(missing_docs)]
 
 use futures::FutureExt;
-use veecle_freertos_integration::{
-    AsyncToBlockingQueueTaskBuilder, Duration, Queue, Task, TaskPriority,
-};
+use veecle_freertos_integration::{AsyncToBlockingQueueTaskBuilder, Duration, Queue, TaskPriority};
 
 pub mod common;
 
@@ -14,17 +12,14 @@ fn queue_async_to_blocking() {
     let mut async_to_blocking = AsyncToBlockingQueueTaskBuilder::new(c"test", queue.clone(), 1)
         .priority(TaskPriority(2))
         .stack_size(1024)
-        .create()
+        .create_detached()
         .unwrap();
 
-    Task::new()
-        .priority(TaskPriority(2))
-        .start(move |_| {
-            assert_eq!(async_to_blocking.send(()).now_or_never(), Some(()));
+    common::start_task_with(TaskPriority(2), 1024, move |_| {
+        assert_eq!(async_to_blocking.send(()).now_or_never(), Some(Ok(())));
 
-            veecle_freertos_integration::CurrentTask::delay(Duration::infinite());
-        })
-        .unwrap();
+        veecle_freertos_integration::CurrentTask::delay(Duration::infinite());
+    });
 
     common::run_freertos_test(move || {
         assert_eq!(queue.receive(Duration::from_ms(1000)), Ok(()));
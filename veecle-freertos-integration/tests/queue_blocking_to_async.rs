@@ -1,9 +1,7 @@
 #![expect(missing_docs)]
 
 use futures::FutureExt;
-use veecle_freertos_integration::{
-    BlockingToAsyncQueueTaskBuilder, Duration, Queue, Task, TaskPriority,
-};
+use veecle_freertos_integration::{BlockingToAsyncQueueTaskBuilder, Duration, Queue, TaskPriority};
 
 pub mod common;
 
@@ -14,19 +12,16 @@ fn queue_blocking_to_async() {
     let mut blocking_to_async = BlockingToAsyncQueueTaskBuilder::new(c"test", queue.clone(), 1)
         .priority(TaskPriority(2))
         .stack_size(1024)
-        .create()
+        .create_detached()
         .unwrap();
 
-    Task::new()
-        .priority(TaskPriority(2))
-        .start(move |_| {
-            assert_eq!(queue.send((), Duration::from_ms(1000)), Ok(()));
+    common::start_task_with(TaskPriority(2), 1024, move |_| {
+        assert_eq!(queue.send((), Duration::from_ms(1000)), Ok(()));
 
-            veecle_freertos_integration::CurrentTask::delay(Duration::infinite());
-        })
-        .unwrap();
+        veecle_freertos_integration::CurrentTask::delay(Duration::infinite());
+    });
 
     common::run_freertos_test(move || {
-        assert_eq!(blocking_to_async.receive().now_or_never(), Some(()));
+        assert_eq!(blocking_to_async.receive().now_or_never(), Some(Some(())));
     })
 }
@@ -0,0 +1,30 @@
+#![expect(missing_docs)]
+
+use futures::FutureExt;
+use veecle_freertos_integration::{BlockingToAsyncQueueTaskBuilder, Duration, Queue, TaskPriority};
+
+pub mod common;
+
+#[common::apply(common::test)]
+fn queue_blocking_to_async_map() {
+    let queue = Queue::<u8>::new(1).expect("queue to be created");
+
+    let mut blocking_to_async = BlockingToAsyncQueueTaskBuilder::new(c"test", queue.clone(), 1)
+        .priority(TaskPriority(2))
+        .map(|byte| u32::from(byte) << 16)
+        .create_detached()
+        .unwrap();
+
+    common::start_task_with(TaskPriority(2), 1024, move |_| {
+        assert_eq!(queue.send(0x12, Duration::from_ms(1000)), Ok(()));
+
+        veecle_freertos_integration::CurrentTask::delay(Duration::infinite());
+    });
+
+    common::run_freertos_test(move || {
+        assert_eq!(
+            blocking_to_async.receive().now_or_never(),
+            Some(Some(0x12_0000))
+        );
+    })
+}
@@ -0,0 +1,32 @@
+#![expect(missing_docs)]
+
+use veecle_freertos_integration::{
+    AsyncToBlockingQueueTaskBuilder, BlockingToAsyncQueueTaskBuilder, Duration, Queue,
+};
+
+pub mod common;
+
+type Item = [u8; 128];
+
+#[common::apply(common::test)]
+fn queue_bridge_calibrate_stack() {
+    common::run_freertos_test(|| {
+        let queue = Queue::<Item>::new(1).unwrap();
+        let calibration = BlockingToAsyncQueueTaskBuilder::new(c"to_async", queue, 1)
+            .poll_interval(Duration::from_ms(10))
+            .calibrate_stack([[1; 128], [2; 128], [3; 128]])
+            .unwrap();
+        assert!(calibration.used > 0);
+        assert!(calibration.used < calibration.stack_size);
+        assert!(calibration.recommended > calibration.used);
+
+        let queue = Queue::<Item>::new(1).unwrap();
+        let calibration = AsyncToBlockingQueueTaskBuilder::new(c"to_blocking", queue, 1)
+            .poll_interval(Duration::from_ms(10))
+            .calibrate_stack([[1; 128], [2; 128], [3; 128]])
+            .unwrap();
+        assert!(calibration.used > 0);
+        assert!(calibration.used < calibration.stack_size);
+        assert!(calibration.recommended > calibration.used);
+    });
+}
@@ -0,0 +1,34 @@
+#![expect(missing_docs)]
+
+use futures::FutureExt;
+use veecle_freertos_integration::{
+    BlockingToAsyncQueueTaskBuilder, CurrentTask, Duration, Queue, TaskPriority,
+};
+use veecle_freertos_sys::bindings::{eTaskGetState, eTaskState_eSuspended};
+
+pub mod common;
+
+#[common::apply(common::test)]
+fn queue_bridge_shutdown() {
+    let queue = Queue::<()>::new(1).expect("queue to be created");
+
+    let (mut receiver, bridge) = BlockingToAsyncQueueTaskBuilder::new(c"bridge", queue, 1)
+        .priority(TaskPriority(2))
+        .poll_interval(Duration::from_ms(10))
+        .create()
+        .unwrap();
+
+    common::run_freertos_test(move || {
+        assert!(!receiver.is_closed());
+
+        bridge.shutdown();
+        CurrentTask::delay(Duration::from_ms(50));
+
+        // SAFETY: The bridge task is never deleted, so its handle is valid.
+        let state = unsafe { eTaskGetState(bridge.task().raw_handle()) };
+        assert_eq!(state, eTaskState_eSuspended);
+
+        assert!(receiver.is_closed());
+        assert_eq!(receiver.receive().now_or_never(), Some(None));
+    });
+}
@@ -0,0 +1,23 @@
+#![expect(missing_docs)]
+
+use veecle_freertos_integration::{
+    AsyncToBlockingQueueTaskBuilder, BlockingToAsyncQueueTaskBuilder, Queue,
+};
+
+pub mod common;
+
+#[common::apply(common::test)]
+fn queue_bridge_task() {
+    let queue = Queue::<()>::new(1).expect("queue to be created");
+
+    let (_receiver, receiver_task) =
+        BlockingToAsyncQueueTaskBuilder::new(c"receiver", queue.clone(), 1)
+            .create()
+            .unwrap();
+    let (_sender, sender_task) = AsyncToBlockingQueueTaskBuilder::new(c"sender", queue, 1)
+        .create()
+        .unwrap();
+
+    assert_eq!(receiver_task.task().get_name().unwrap(), "receiver");
+    assert_eq!(sender_task.task().get_name().unwrap(), "sender");
+}
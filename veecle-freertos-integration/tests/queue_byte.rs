@@ -0,0 +1,44 @@
+#![expect(missing_docs)]
+
+use veecle_freertos_integration::{ByteQueue, CurrentTask, Duration, FreeRtosError};
+
+pub mod common;
+
+#[common::apply(common::test)]
+fn queue_byte() {
+    const FRAME: [u8; 8] = [0xCA, 0xFE, 0, 1, 2, 3, 4, 5];
+
+    let queue: ByteQueue<8> = ByteQueue::new(2).unwrap();
+    let receiver = queue.clone();
+
+    common::start_task(move |_| {
+        assert_eq!(receiver.recv_bytes(Duration::from_ms(1000)), Ok(FRAME));
+        assert_eq!(
+            receiver.recv_bytes(Duration::from_ms(1000)),
+            Ok([1, 2, 0, 0, 0, 0, 0, 0])
+        );
+
+        common::end_scheduler();
+    });
+
+    common::start_task(move |_| {
+        assert_eq!(
+            queue.send_bytes(&[0; 9], Duration::zero()),
+            Err(FreeRtosError::FrameTooLong)
+        );
+
+        let full: ByteQueue<2> = ByteQueue::new(1).unwrap();
+        full.send_bytes(&[1], Duration::zero()).unwrap();
+        assert_eq!(
+            full.send_bytes(&[2], Duration::zero()),
+            Err(FreeRtosError::QueueFull)
+        );
+
+        queue.send_bytes(&FRAME, Duration::zero()).unwrap();
+        queue.send_bytes(&[1, 2], Duration::zero()).unwrap();
+
+        CurrentTask::suspend();
+    });
+
+    veecle_freertos_integration::scheduler::start_scheduler();
+}
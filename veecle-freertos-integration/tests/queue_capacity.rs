@@ -0,0 +1,21 @@
+#![expect(missing_docs)]
+
+use veecle_freertos_integration::{Duration, Queue};
+
+pub mod common;
+
+#[common::apply(common::test)]
+fn queue_capacity() {
+    let queue: Queue<u8> = Queue::new(5).expect("queue to be created");
+    assert_eq!(queue.capacity(), 5);
+
+    // SAFETY: The handle is a valid handle for a `Queue<u8>`.
+    let from_raw_queue: Queue<u8> = unsafe { Queue::from_raw_handle(queue.raw_handle()) };
+    assert_eq!(from_raw_queue.capacity(), 5);
+
+    common::run_freertos_test(move || {
+        assert_eq!(queue.send(1, Duration::zero()), Ok(()));
+        assert_eq!(queue.capacity(), 5);
+        assert_eq!(queue.spaces_available(), 4);
+    });
+}
@@ -0,0 +1,22 @@
+#![expect(missing_docs)]
+
+use veecle_freertos_integration::{Duration, Queue};
+
+pub mod common;
+
+#[common::apply(common::test)]
+fn queue_drain() {
+    let queue = Queue::new(5).expect("queue to be created");
+
+    common::run_freertos_test(move || {
+        for item in 1..=3_u8 {
+            assert_eq!(queue.send(item, Duration::zero()), Ok(()));
+        }
+
+        let drained: Vec<u8> = queue.drain().collect();
+        assert_eq!(drained, [1, 2, 3]);
+
+        assert_eq!(queue.messages_waiting(), 0);
+        assert_eq!(queue.drain().next(), None);
+    });
+}
@@ -0,0 +1,33 @@
+#![expect(missing_docs)]
+
+pub mod common;
+
+#[common::apply(common::test)]
+fn queue_new_static() {
+    #[cfg(configSUPPORT_STATIC_ALLOCATION)]
+    {
+        use veecle_freertos_integration::{Duration, FreeRtosError, Queue};
+        use veecle_freertos_sys::bindings::StaticQueue_t;
+
+        fn queue_struct() -> &'static mut StaticQueue_t {
+            // SAFETY: `StaticQueue_t` is a plain C struct, all zeroes is a valid value.
+            Box::leak(Box::new(unsafe { core::mem::zeroed() }))
+        }
+
+        let too_small: &'static mut [u8] = Box::leak(vec![0; 3].into_boxed_slice());
+        assert_eq!(
+            Queue::<u32>::new_static(too_small, queue_struct(), 2).err(),
+            Some(FreeRtosError::InvalidQueueSize)
+        );
+
+        let storage: &'static mut [u8] =
+            Box::leak(vec![0; 2 * size_of::<u32>()].into_boxed_slice());
+        let queue = Queue::<u32>::new_static(storage, queue_struct(), 2).unwrap();
+        assert_eq!(queue.capacity(), 2);
+
+        common::run_freertos_test(move || {
+            assert_eq!(queue.send(42, Duration::zero()), Ok(()));
+            assert_eq!(queue.receive(Duration::zero()), Ok(42));
+        });
+    }
+}
@@ -0,0 +1,35 @@
+#![expect(missing_docs)]
+
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+use veecle_freertos_integration::{Duration, InterruptContext, Queue};
+
+pub mod common;
+
+static FULL_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+fn on_full() {
+    FULL_COUNT.fetch_add(1, Ordering::Relaxed);
+}
+
+#[common::apply(common::test)]
+fn queue_on_full() {
+    let queue = Queue::new_with_on_full(1, on_full).expect("queue to be created");
+    let clone = queue.clone();
+
+    common::run_freertos_test(move || {
+        assert_eq!(queue.send((), Duration::zero()), Ok(()));
+        assert_eq!(FULL_COUNT.load(Ordering::Relaxed), 0);
+
+        assert_eq!(queue.send((), Duration::zero()), Err(()));
+        assert_eq!(FULL_COUNT.load(Ordering::Relaxed), 1);
+
+        let mut interrupt_context = InterruptContext::default();
+        assert_eq!(queue.send_from_isr(&mut interrupt_context, ()), Err(()));
+        assert_eq!(FULL_COUNT.load(Ordering::Relaxed), 2);
+
+        // Clones share the callback of the queue they were created from.
+        assert_eq!(clone.send((), Duration::zero()), Err(()));
+        assert_eq!(FULL_COUNT.load(Ordering::Relaxed), 3);
+    });
+}
@@ -0,0 +1,28 @@
+#![expect(missing_docs)]
+
+use std::sync::Arc;
+
+use veecle_freertos_integration::{Duration, Queue};
+
+pub mod common;
+
+#[common::apply(common::test)]
+fn queue_overwrite_oldest() {
+    common::run_freertos_test(|| {
+        let queue: Queue<Arc<u32>> = Queue::new(2).unwrap();
+        let first = Arc::new(1);
+
+        assert_eq!(queue.send_overwriting_oldest(Arc::clone(&first)), Ok(None));
+        assert_eq!(queue.send_overwriting_oldest(Arc::new(2)), Ok(None));
+        assert_eq!(Arc::strong_count(&first), 2);
+
+        let evicted = queue.send_overwriting_oldest(Arc::new(3)).unwrap();
+        assert_eq!(evicted.as_deref(), Some(&1));
+        drop(evicted);
+        assert_eq!(Arc::strong_count(&first), 1);
+
+        assert_eq!(*queue.receive(Duration::zero()).unwrap(), 2);
+        assert_eq!(*queue.receive(Duration::zero()).unwrap(), 3);
+        assert!(queue.receive(Duration::zero()).is_err());
+    });
+}
@@ -0,0 +1,36 @@
+#![expect(missing_docs)]
+
+use veecle_freertos_integration::{Duration, PointerQueue};
+
+pub mod common;
+
+#[derive(Debug)]
+struct Image {
+    id: u32,
+    pixels: [u8; 4096],
+}
+
+#[common::apply(common::test)]
+fn queue_pointer() {
+    common::run_freertos_test(|| {
+        let queue: PointerQueue<Image> = PointerQueue::new(2).unwrap();
+
+        let mut image = Box::new(Image {
+            id: 3,
+            pixels: [0; 4096],
+        });
+        image.pixels[4095] = 0xFF;
+        let address = &raw const *image;
+
+        queue.send_boxed(image, Duration::zero()).unwrap();
+        assert_eq!(queue.messages_waiting(), 1);
+
+        let received = queue.receive_boxed(Duration::zero()).unwrap();
+        // Only the pointer moved through the queue.
+        assert_eq!(&raw const *received, address);
+        assert_eq!(received.id, 3);
+        assert_eq!(received.pixels[4095], 0xFF);
+
+        assert!(queue.receive_boxed(Duration::zero()).is_err());
+    });
+}
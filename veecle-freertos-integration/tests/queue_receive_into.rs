@@ -0,0 +1,42 @@
+#![expect(missing_docs)]
+
+use core::mem::MaybeUninit;
+
+use veecle_freertos_integration::{Duration, FreeRtosError, Queue};
+
+pub mod common;
+
+#[derive(Debug, PartialEq)]
+struct Frame {
+    sequence: u32,
+    payload: [u8; 256],
+}
+
+#[common::apply(common::test)]
+fn queue_receive_into() {
+    common::run_freertos_test(|| {
+        let queue: Queue<Frame> = Queue::new(1).unwrap();
+        let mut out = MaybeUninit::<Frame>::uninit();
+
+        assert_eq!(
+            queue.receive_into(&mut out, Duration::zero()),
+            Err(FreeRtosError::QueueReceiveTimeout)
+        );
+
+        queue
+            .send(
+                Frame {
+                    sequence: 7,
+                    payload: [0xAB; 256],
+                },
+                Duration::zero(),
+            )
+            .unwrap();
+
+        queue.receive_into(&mut out, Duration::zero()).unwrap();
+        // SAFETY: `receive_into` succeeded, so `out` holds the received frame.
+        let frame = unsafe { out.assume_init_ref() };
+        assert_eq!(frame.sequence, 7);
+        assert_eq!(frame.payload, [0xAB; 256]);
+    });
+}
@@ -0,0 +1,22 @@
+#![expect(missing_docs)]
+
+pub mod common;
+
+#[common::apply(common::test)]
+fn queue_registry_name() {
+    #[cfg(configQUEUE_REGISTRY_SIZE)]
+    {
+        use veecle_freertos_integration::{Duration, Queue};
+
+        let queue: Queue<u8> = Queue::new(2).unwrap();
+        assert_eq!(queue.registry_name(), None);
+
+        queue.set_registry_name(c"telemetry");
+        assert_eq!(queue.registry_name(), Some(c"telemetry"));
+
+        // The handle keeps working after registering.
+        queue.send(1, Duration::zero()).unwrap();
+        assert_eq!(queue.messages_waiting(), 1);
+        assert_eq!(queue.receive(Duration::zero()), Ok(1));
+    }
+}
@@ -0,0 +1,23 @@
+#![expect(missing_docs)]
+
+use veecle_freertos_integration::{Duration, Queue};
+
+pub mod common;
+
+#[common::apply(common::test)]
+fn queue_send_iter() {
+    common::run_freertos_test(|| {
+        let queue: Queue<u32> = Queue::new(4).unwrap();
+
+        assert!(queue.send_iter([1, 2, 3], Duration::zero()).is_ok());
+        assert_eq!(queue.messages_waiting(), 3);
+
+        // Only the first item fits, the failed and remaining items are returned.
+        let (failed, remaining) = queue
+            .send_iter(4..=6, Duration::zero())
+            .expect_err("the queue should fill up");
+        assert_eq!(failed, 5);
+        assert_eq!(remaining.collect::<Vec<_>>(), [6]);
+        assert_eq!(queue.drain().collect::<Vec<_>>(), [1, 2, 3, 4]);
+    });
+}
@@ -0,0 +1,31 @@
+#![expect(missing_docs)]
+
+use veecle_freertos_integration::{Duration, Queue};
+
+pub mod common;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Sample {
+    channel: u8,
+    value: u32,
+}
+
+#[common::apply(common::test)]
+fn queue_send_ref() {
+    common::run_freertos_test(|| {
+        let queue: Queue<Sample> = Queue::new(3).unwrap();
+        let sample = Sample {
+            channel: 1,
+            value: 42,
+        };
+
+        let mut sent = 0;
+        while queue.send_ref(&sample, Duration::zero()) {
+            sent += 1;
+        }
+        assert_eq!(sent, 3);
+        assert!(!queue.send_ref(&sample, Duration::zero()));
+
+        assert_eq!(queue.drain().collect::<Vec<_>>(), [sample; 3]);
+    });
+}
@@ -0,0 +1,24 @@
+#![expect(missing_docs)]
+
+use veecle_freertos_integration::{Duration, FreeRtosError, Queue};
+
+pub mod common;
+
+#[common::apply(common::test)]
+fn queue_send_with_error() {
+    common::run_freertos_test(|| {
+        let queue: Queue<u8> = Queue::new(1).unwrap();
+
+        assert_eq!(queue.send_with_error(1, Duration::zero()), Ok(()));
+        assert_eq!(
+            queue.send_with_error(2, Duration::zero()),
+            Err((2, FreeRtosError::QueueFull))
+        );
+        assert_eq!(
+            queue.send_with_error(3, Duration::from_ms(10)),
+            Err((3, FreeRtosError::QueueFull))
+        );
+
+        assert_eq!(queue.receive(Duration::zero()), Ok(1));
+    });
+}
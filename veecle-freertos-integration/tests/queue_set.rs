@@ -0,0 +1,32 @@
+#![expect(missing_docs)]
+
+use veecle_freertos_integration::{Duration, Queue, QueueSet};
+
+pub mod common;
+
+#[common::apply(common::test)]
+fn queue_set() {
+    let first: Queue<u8> = Queue::new(2).expect("queue to be created");
+    let second: Queue<u16> = Queue::new(2).expect("queue to be created");
+
+    let set = QueueSet::new(4).expect("queue set to be created");
+    let first_member = set.add(&first).expect("queue to be added");
+    let second_member = set.add(&second).expect("queue to be added");
+
+    common::run_freertos_test(move || {
+        assert_eq!(set.select(Duration::zero()), None);
+
+        assert_eq!(second.send(7, Duration::zero()), Ok(()));
+
+        let selected = set
+            .select(Duration::from_ms(100))
+            .expect("a member to be ready");
+        assert_eq!(selected, second_member);
+        assert_ne!(selected, first_member);
+        assert!(selected.is(&second));
+        assert!(!selected.is(&first));
+
+        assert_eq!(second.receive(Duration::zero()), Ok(7));
+        assert_eq!(set.select(Duration::zero()), None);
+    });
+}
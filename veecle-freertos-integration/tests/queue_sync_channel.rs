@@ -0,0 +1,29 @@
+#![expect(missing_docs)]
+
+use veecle_freertos_integration::{CurrentTask, Duration, sync_channel};
+
+pub mod common;
+
+#[common::apply(common::test)]
+fn queue_sync_channel() {
+    let (sender, receiver) = sync_channel::<u32>(2).unwrap();
+
+    common::start_task(move |_| {
+        for expected in 0..10 {
+            assert_eq!(receiver.recv(Duration::from_ms(1000)), Ok(expected));
+        }
+        assert!(receiver.recv(Duration::from_ms(10)).is_err());
+
+        common::end_scheduler();
+    });
+
+    common::start_task(move |_| {
+        for value in 0..10 {
+            sender.send(value, Duration::from_ms(1000)).unwrap();
+        }
+
+        CurrentTask::suspend();
+    });
+
+    veecle_freertos_integration::scheduler::start_scheduler();
+}
@@ -0,0 +1,34 @@
+#![expect(missing_docs)]
+
+use veecle_freertos_integration::{CurrentTask, Duration, Instant, Queue};
+
+pub mod common;
+
+#[common::apply(common::test)]
+fn queue_timeout_remaining() {
+    let queue: Queue<u8> = Queue::new(1).unwrap();
+
+    common::run_freertos_test(move || {
+        let sender = queue.clone();
+        common::start_task(move |_| {
+            CurrentTask::delay(Duration::from_ms(10));
+            sender.send(1, Duration::zero()).unwrap();
+            CurrentTask::suspend();
+        });
+
+        let start = Instant::now();
+        let deadline = start + Duration::from_ms(30);
+
+        assert_eq!(queue.receive_timeout_remaining(deadline), Ok(1));
+        assert!(start.elapsed() >= Duration::from_ms(10));
+
+        // The second wait only gets what is left of the overall budget, instead of another 30 ms.
+        assert!(queue.receive_timeout_remaining(deadline).is_err());
+        assert!(start.elapsed() >= Duration::from_ms(30));
+        assert!(start.elapsed() < Duration::from_ms(40));
+
+        assert_eq!(deadline.remaining(), Duration::zero());
+        queue.send_timeout_remaining(2, deadline).unwrap();
+        assert_eq!(queue.send_timeout_remaining(3, deadline), Err(3));
+    });
+}
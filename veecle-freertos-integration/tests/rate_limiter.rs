@@ -0,0 +1,22 @@
+#![expect(missing_docs)]
+
+use veecle_freertos_integration::{CurrentTask, Duration, RateLimiter};
+
+pub mod common;
+
+#[common::apply(common::test)]
+fn rate_limiter() {
+    common::run_freertos_test(|| {
+        let mut limiter = RateLimiter::new(Duration::from_ms(20));
+
+        assert!(limiter.try_acquire());
+        assert!(!limiter.try_acquire());
+
+        CurrentTask::delay(Duration::from_ms(10));
+        assert!(!limiter.try_acquire());
+
+        CurrentTask::delay(Duration::from_ms(10));
+        assert!(limiter.try_acquire());
+        assert!(!limiter.try_acquire());
+    });
+}
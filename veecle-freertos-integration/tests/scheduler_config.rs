@@ -0,0 +1,12 @@
+#![expect(missing_docs)]
+
+use veecle_freertos_integration::scheduler::{preemption_enabled, time_slicing_enabled};
+use veecle_freertos_sys::bindings::{configUSE_PREEMPTION, configUSE_TIME_SLICING};
+
+pub mod common;
+
+#[common::apply(common::test)]
+fn scheduler_config() {
+    assert_eq!(preemption_enabled(), configUSE_PREEMPTION == 1);
+    assert_eq!(time_slicing_enabled(), configUSE_TIME_SLICING == 1);
+}
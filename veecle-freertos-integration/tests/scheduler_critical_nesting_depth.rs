@@ -0,0 +1,21 @@
+#![expect(missing_docs)]
+
+use veecle_freertos_integration::scheduler::{critical_nesting_depth, with_critical_section};
+
+pub mod common;
+
+#[common::apply(common::test)]
+fn scheduler_critical_nesting_depth() {
+    common::run_freertos_test(|| {
+        assert_eq!(critical_nesting_depth(), 0);
+
+        let depths = with_critical_section(|| {
+            let outer = critical_nesting_depth();
+            let inner = with_critical_section(critical_nesting_depth);
+            (outer, inner)
+        });
+        assert_eq!(depths, (1, 2));
+
+        assert_eq!(critical_nesting_depth(), 0);
+    });
+}
@@ -0,0 +1,26 @@
+#![expect(missing_docs)]
+
+use core::cell::Cell;
+
+use veecle_freertos_integration::scheduler::with_critical_section;
+
+pub mod common;
+
+#[common::apply(common::test)]
+fn scheduler_critical_section() {
+    common::run_freertos_test(move || {
+        let counter = Cell::new(1);
+
+        let doubled = with_critical_section(|| {
+            counter.set(counter.get() + 1);
+            counter.get() * 2
+        });
+
+        assert_eq!(doubled, 4);
+        assert_eq!(counter.get(), 2);
+
+        // Nested critical sections are allowed.
+        let nested = with_critical_section(|| with_critical_section(|| counter.get() + 1));
+        assert_eq!(nested, 3);
+    });
+}
@@ -0,0 +1,31 @@
+//! Host test using the system allocator, the FreeRTOS allocator is not safe to use from the host thread.
+
+use std::sync::atomic::{AtomicU32, Ordering};
+
+use veecle_freertos_integration::scheduler::{
+    TICK_RATE_TOLERANCE_PERCENT, effective_tick_rate, spawn_on_host_thread,
+};
+use veecle_freertos_integration::{hooks, tick_rate_hz};
+
+#[test]
+fn scheduler_effective_tick_rate() {
+    static MISMATCHES: AtomicU32 = AtomicU32::new(0);
+
+    hooks::set_on_tick_rate_mismatch(|_, _| {
+        MISMATCHES.fetch_add(1, Ordering::Relaxed);
+    });
+
+    let scheduler = spawn_on_host_thread();
+
+    let measured = scheduler.run(effective_tick_rate);
+    let configured = tick_rate_hz();
+    let tolerance = configured / 100 * TICK_RATE_TOLERANCE_PERCENT;
+
+    assert!(
+        measured.abs_diff(configured) <= tolerance,
+        "measured {measured} Hz, configured {configured} Hz"
+    );
+    assert_eq!(MISMATCHES.load(Ordering::Relaxed), 0);
+
+    scheduler.shutdown();
+}
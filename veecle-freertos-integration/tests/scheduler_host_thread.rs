@@ -0,0 +1,21 @@
+//! Host test using the system allocator, the FreeRTOS allocator is not safe to use from the host thread.
+
+use veecle_freertos_integration::scheduler::{get_tick_count, spawn_on_host_thread};
+use veecle_freertos_integration::{CurrentTask, Duration, Task};
+
+#[test]
+fn scheduler_host_thread() {
+    let scheduler = spawn_on_host_thread();
+
+    let name = scheduler.run(|| Task::current().unwrap().get_name().unwrap());
+    assert_eq!(name, "host_runner");
+
+    let start = scheduler.run(get_tick_count);
+    let end = scheduler.run(|| {
+        CurrentTask::delay(Duration::from_ms(10));
+        get_tick_count()
+    });
+    assert!(end > start);
+
+    scheduler.shutdown();
+}
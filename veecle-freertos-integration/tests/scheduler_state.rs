@@ -0,0 +1,24 @@
+#![expect(missing_docs)]
+
+use veecle_freertos_integration::scheduler::{SchedulerState, state};
+use veecle_freertos_sys::bindings::{vTaskSuspendAll, xTaskResumeAll};
+
+pub mod common;
+
+#[common::apply(common::test)]
+fn scheduler_state() {
+    assert_eq!(state(), SchedulerState::NotStarted);
+
+    common::run_freertos_test(|| {
+        assert_eq!(state(), SchedulerState::Running);
+
+        // SAFETY: Paired with the `xTaskResumeAll` below.
+        unsafe { vTaskSuspendAll() };
+        let suspended = state();
+        // SAFETY: The scheduler was suspended above.
+        unsafe { xTaskResumeAll() };
+
+        assert_eq!(suspended, SchedulerState::Suspended);
+        assert_eq!(state(), SchedulerState::Running);
+    });
+}
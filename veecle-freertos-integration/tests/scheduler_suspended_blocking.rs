@@ -0,0 +1,44 @@
+#![expect(missing_docs)]
+
+pub mod common;
+
+// A panic can't unwind out of a FreeRTOS task, so the panic hook redirects the program flow after checking the
+// message.
+
+#[common::apply(common::test)]
+fn scheduler_suspended_blocking() {
+    #[cfg(debug_assertions)]
+    {
+        use veecle_freertos_integration::{CurrentTask, Duration, Task};
+
+        Task::new()
+            .start(|_| {
+                std::panic::set_hook(Box::new(|info| {
+                    assert!(
+                        info.to_string().contains(
+                            "blocking FreeRTOS API called while the scheduler is suspended"
+                        ),
+                        "{info}"
+                    );
+
+                    // SAFETY: The scheduler was suspended below.
+                    unsafe {
+                        veecle_freertos_sys::bindings::xTaskResumeAll();
+                    }
+                    common::end_scheduler();
+                }));
+
+                // SAFETY: No requirements on the caller in non-ISR contexts.
+                unsafe {
+                    veecle_freertos_sys::bindings::vTaskSuspendAll();
+                }
+
+                CurrentTask::delay(Duration::from_ms(10));
+
+                unreachable!("delay should have panicked");
+            })
+            .unwrap();
+
+        veecle_freertos_integration::scheduler::start_scheduler();
+    }
+}
@@ -0,0 +1,18 @@
+#![expect(missing_docs)]
+
+use veecle_freertos_integration::scheduler::{get_tick_count, get_tick_count_from_isr};
+use veecle_freertos_sys::bindings::vTaskDelay;
+
+pub mod common;
+
+#[common::apply(common::test)]
+fn scheduler_tick_count_from_isr() {
+    common::run_freertos_test(|| {
+        let ticks = get_tick_count_from_isr();
+        vTaskDelay(10 / veecle_freertos_sys::bindings::portTICK_PERIOD_MS());
+
+        let later = get_tick_count_from_isr();
+        assert!(ticks < later);
+        assert!(later <= get_tick_count());
+    });
+}
@@ -0,0 +1,47 @@
+#![expect(missing_docs)]
+
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use futures::FutureExt;
+use veecle_freertos_integration::task::block_on_future;
+use veecle_freertos_integration::{AsyncSemaphore, CurrentTask, Duration};
+
+pub mod common;
+
+#[common::apply(common::test)]
+fn semaphore_async() {
+    static ACQUIRED: AtomicBool = AtomicBool::new(false);
+
+    let semaphore = AsyncSemaphore::new(2, 2).unwrap();
+    let waiter = semaphore.clone();
+
+    common::start_task(move |_| {
+        assert_eq!(waiter.acquire().now_or_never(), Some(()));
+        assert_eq!(waiter.acquire().now_or_never(), Some(()));
+        assert_eq!(waiter.available(), 0);
+        assert!(!waiter.try_acquire());
+
+        block_on_future(waiter.acquire());
+        ACQUIRED.store(true, Ordering::Release);
+
+        CurrentTask::suspend();
+    });
+
+    common::start_task(move |_| {
+        CurrentTask::delay(Duration::from_ms(10));
+        assert!(!ACQUIRED.load(Ordering::Acquire));
+
+        semaphore.release().unwrap();
+        CurrentTask::delay(Duration::from_ms(10));
+        assert!(ACQUIRED.load(Ordering::Acquire));
+        assert_eq!(semaphore.available(), 0);
+
+        semaphore.release().unwrap();
+        semaphore.release().unwrap();
+        assert!(semaphore.release().is_err());
+
+        common::end_scheduler();
+    });
+
+    veecle_freertos_integration::scheduler::start_scheduler();
+}
@@ -0,0 +1,49 @@
+#![expect(missing_docs)]
+
+use core::sync::atomic::{AtomicBool, Ordering};
+
+use veecle_freertos_integration::task::with_boosted_priority;
+use veecle_freertos_integration::{CurrentTask, Duration, Task, TaskPriority};
+use veecle_freertos_sys::bindings::taskYIELD;
+
+pub mod common;
+
+static PEER_RAN: AtomicBool = AtomicBool::new(false);
+
+#[common::apply(common::test)]
+fn task_boosted_priority() {
+    common::run_freertos_test(|| {
+        let current = Task::current().unwrap();
+        assert_eq!(current.get_priority().0, 1);
+
+        let result = with_boosted_priority(TaskPriority(3), || {
+            assert_eq!(current.get_priority().0, 3);
+
+            Task::new()
+                .priority(TaskPriority(2))
+                .start(|_| {
+                    PEER_RAN.store(true, Ordering::SeqCst);
+                    CurrentTask::delay(Duration::infinite());
+                })
+                .unwrap();
+
+            // The mid-priority peer can't preempt the boosted task.
+            taskYIELD();
+            assert!(!PEER_RAN.load(Ordering::SeqCst));
+
+            42
+        });
+
+        // The peer preempts as soon as the priority is restored.
+        assert_eq!(result, 42);
+        assert_eq!(current.get_priority().0, 1);
+        assert!(PEER_RAN.load(Ordering::SeqCst));
+
+        // The priority is also restored if `f` unwinds.
+        let panicked = std::panic::catch_unwind(|| {
+            with_boosted_priority(TaskPriority(3), || panic!("boosted"))
+        });
+        assert!(panicked.is_err());
+        assert_eq!(current.get_priority().0, 1);
+    });
+}
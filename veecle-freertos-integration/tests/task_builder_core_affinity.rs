@@ -0,0 +1,31 @@
+#![expect(missing_docs)]
+
+use veecle_freertos_integration::{
+    CoreAffinityMask, CurrentTask, Duration, Queue, Task, TaskPriority,
+};
+
+pub mod common;
+
+#[common::apply(common::test)]
+fn task_builder_core_affinity() {
+    let ran: Queue<()> = Queue::new(1).unwrap();
+
+    let task = Task::new()
+        .priority(TaskPriority(2))
+        .core_affinity(CoreAffinityMask(0b1))
+        .start({
+            let ran = ran.clone();
+            move |_| {
+                ran.send((), Duration::zero()).unwrap();
+                CurrentTask::suspend();
+            }
+        })
+        .unwrap();
+
+    // Pinned to core 0 on SMP configurations, and the only core otherwise.
+    assert_eq!(task.core_affinity(), CoreAffinityMask(0b1));
+
+    common::run_freertos_test(move || {
+        assert_eq!(ran.receive(Duration::from_ms(1000)), Ok(()));
+    });
+}
@@ -0,0 +1,36 @@
+#![expect(missing_docs)]
+
+use std::num::NonZeroU32;
+
+use veecle_freertos_integration::{CurrentTask, FreeRtosError, task};
+use veecle_freertos_sys::bindings::shim_portGET_RUN_TIME_COUNTER_VALUE;
+
+pub mod common;
+
+#[common::apply(common::test)]
+fn task_busy_delay() {
+    common::run_freertos_test(|| {
+        // SAFETY: No requirements on the caller.
+        let start = unsafe { shim_portGET_RUN_TIME_COUNTER_VALUE() };
+        CurrentTask::busy_delay(2);
+        // SAFETY: No requirements on the caller.
+        let end = unsafe { shim_portGET_RUN_TIME_COUNTER_VALUE() };
+
+        assert!(end.wrapping_sub(start) >= 2);
+
+        assert_eq!(
+            CurrentTask::busy_delay_us(10),
+            Err(FreeRtosError::RunTimeCounterFrequencyUnknown)
+        );
+
+        // With a frequency of 1 kHz, 1500 us round up to two counts.
+        task::set_run_time_counter_frequency(NonZeroU32::new(1000).unwrap());
+        // SAFETY: No requirements on the caller.
+        let start = unsafe { shim_portGET_RUN_TIME_COUNTER_VALUE() };
+        assert_eq!(CurrentTask::busy_delay_us(1500), Ok(()));
+        // SAFETY: No requirements on the caller.
+        let end = unsafe { shim_portGET_RUN_TIME_COUNTER_VALUE() };
+
+        assert!(end.wrapping_sub(start) >= 2);
+    });
+}
@@ -0,0 +1,33 @@
+#![expect(missing_docs)]
+
+pub mod common;
+
+#[common::apply(common::test)]
+fn task_catch_panics() {
+    #[cfg(panic = "unwind")]
+    {
+        use std::sync::atomic::{AtomicBool, Ordering};
+
+        use veecle_freertos_integration::{CurrentTask, Duration, Task, TaskPriority};
+
+        static HOOK_CALLED: AtomicBool = AtomicBool::new(false);
+
+        common::run_freertos_test(|| {
+            veecle_freertos_integration::hooks::set_on_task_panic(|task, payload| {
+                assert_eq!(task.get_name().unwrap(), "panicking");
+                assert_eq!(payload.downcast_ref::<&str>(), Some(&"boom"));
+                HOOK_CALLED.store(true, Ordering::Release);
+            });
+
+            Task::new()
+                .name(c"panicking")
+                .priority(TaskPriority(2))
+                .catch_panics()
+                .start(|_| panic!("boom"))
+                .unwrap();
+
+            CurrentTask::delay(Duration::from_ms(10));
+            assert!(HOOK_CALLED.load(Ordering::Acquire));
+        });
+    }
+}
@@ -0,0 +1,29 @@
+#![expect(missing_docs)]
+
+use veecle_freertos_integration::{CoreAffinityMask, Task};
+
+pub mod common;
+
+#[common::apply(common::test)]
+fn task_core_affinity() {
+    let task = Task::new()
+        .start(|_| unreachable!("we don't start the scheduler"))
+        .unwrap();
+
+    #[cfg(configUSE_CORE_AFFINITY)]
+    {
+        // SAFETY: The task handle is valid, it was just created.
+        unsafe {
+            veecle_freertos_sys::bindings::vTaskCoreAffinitySet(task.raw_handle(), 0b10);
+        }
+        assert_eq!(task.core_affinity(), CoreAffinityMask(0b10));
+        assert!(task.core_affinity().contains(1));
+        assert!(!task.core_affinity().contains(0));
+    }
+
+    #[cfg(not(configUSE_CORE_AFFINITY))]
+    {
+        assert_eq!(task.core_affinity(), CoreAffinityMask(1));
+        assert!(task.core_affinity().contains(0));
+    }
+}
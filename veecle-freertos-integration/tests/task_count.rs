@@ -0,0 +1,20 @@
+#![expect(missing_docs)]
+
+use veecle_freertos_integration::{CurrentTask, Task, task};
+
+pub mod common;
+
+#[common::apply(common::test)]
+fn task_count() {
+    common::run_freertos_test(|| {
+        // The idle task, the timer daemon task and this task.
+        let before = task::count();
+        assert!(before >= 3);
+
+        for _ in 0..3 {
+            Task::new().start(|_| CurrentTask::suspend()).unwrap();
+        }
+
+        assert_eq!(task::count(), before + 3);
+    });
+}
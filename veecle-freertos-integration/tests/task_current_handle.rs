@@ -0,0 +1,21 @@
+#![expect(missing_docs)]
+
+use veecle_freertos_integration::{CurrentTask, Task};
+
+pub mod common;
+
+#[common::apply(common::test)]
+fn task_current_handle() {
+    Task::new()
+        .name(c"foobar")
+        .start(|task| {
+            let handle = CurrentTask::handle();
+            assert_eq!(handle.raw_handle(), task.raw_handle());
+            assert_eq!(handle.get_name().unwrap(), "foobar");
+
+            common::end_scheduler();
+        })
+        .unwrap();
+
+    veecle_freertos_integration::scheduler::start_scheduler();
+}
@@ -0,0 +1,20 @@
+#![expect(missing_docs)]
+
+use veecle_freertos_integration::CurrentTask;
+use veecle_freertos_integration::scheduler::get_tick_count;
+
+pub mod common;
+
+#[common::apply(common::test)]
+fn task_delay_until_next_tick() {
+    common::run_freertos_test(|| {
+        // Align to a tick boundary first, so no tick passes between reading the tick count and blocking.
+        CurrentTask::delay_until_next_tick();
+
+        for _ in 0..10 {
+            let ticks = get_tick_count();
+            CurrentTask::delay_until_next_tick();
+            assert_eq!(get_tick_count(), ticks + 1);
+        }
+    });
+}
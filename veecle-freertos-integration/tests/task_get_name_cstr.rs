@@ -0,0 +1,24 @@
+#![expect(missing_docs)]
+
+use veecle_freertos_integration::Task;
+
+pub mod common;
+
+#[common::apply(common::test)]
+fn task_get_name_cstr() {
+    let tagged = Task::new()
+        .name(c"t\u{e4}sk")
+        .start(|_| unreachable!("we don't start the scheduler"))
+        .unwrap();
+
+    assert_eq!(tagged.get_name_cstr(), c"t\u{e4}sk");
+    assert_eq!(tagged.get_name().unwrap(), "t\u{e4}sk");
+
+    let invalid = Task::new()
+        .name(c"raw\xff")
+        .start(|_| unreachable!("we don't start the scheduler"))
+        .unwrap();
+
+    assert_eq!(invalid.get_name_cstr(), c"raw\xff");
+    assert!(invalid.get_name().is_err());
+}
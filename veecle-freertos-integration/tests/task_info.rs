@@ -0,0 +1,29 @@
+#![expect(missing_docs)]
+
+use veecle_freertos_integration::task::TaskState;
+use veecle_freertos_integration::{CurrentTask, Task, TaskPriority};
+
+pub mod common;
+
+#[common::apply(common::test)]
+fn task_info() {
+    let worker = Task::new()
+        .name(c"info_worker")
+        .priority(TaskPriority(3))
+        .start(|_| CurrentTask::suspend())
+        .unwrap();
+
+    common::run_freertos_test(move || {
+        let info = worker.info(false);
+        assert_eq!(info.name, "info_worker");
+        assert_eq!(info.priority.0, 3);
+        assert_eq!(info.base_priority.0, 3);
+        assert_eq!(info.state, TaskState::Suspended);
+        assert!(!info.stack_base.is_null());
+        assert_eq!(info.stack_high_water_mark, None);
+
+        let info = CurrentTask::handle().info(true);
+        assert_eq!(info.state, TaskState::Running);
+        assert!(info.stack_high_water_mark.is_some_and(|words| words > 0));
+    });
+}
@@ -0,0 +1,26 @@
+#![expect(missing_docs)]
+
+use veecle_freertos_integration::{Task, TaskPriority};
+
+pub mod common;
+
+#[common::apply(common::test)]
+fn task_join_handle() {
+    common::run_freertos_test(|| {
+        // Lower priority than the test task, so joining has to wait for the result.
+        let lower = Task::new()
+            .priority(TaskPriority(0))
+            .start_with_result(|_| (1..=10u32).sum::<u32>())
+            .unwrap();
+        assert!(!lower.is_finished());
+        assert_eq!(lower.join(), 55);
+
+        // Higher priority than the test task, so the result is available before joining.
+        let higher = Task::new()
+            .priority(TaskPriority(2))
+            .start_with_result(|task| task.get_name().unwrap())
+            .unwrap();
+        assert!(higher.is_finished());
+        assert_eq!(higher.join(), "rust_task");
+    });
+}
@@ -0,0 +1,26 @@
+#![expect(missing_docs)]
+
+pub mod common;
+
+#[common::apply(common::test)]
+#[requires(all(
+    any(
+        configUSE_STATS_FORMATTING_FUNCTIONS = "1",
+        configUSE_STATS_FORMATTING_FUNCTIONS = "2"
+    ),
+    configUSE_TRACE_FACILITY
+))]
+fn task_list() {
+    common::run_freertos_test(move || {
+        let name = veecle_freertos_integration::Task::current()
+            .unwrap()
+            .get_name()
+            .unwrap();
+        let list = veecle_freertos_integration::task::list();
+
+        assert!(
+            list.lines().any(|line| line.starts_with(name.as_str())),
+            "{list}"
+        );
+    });
+}
@@ -0,0 +1,50 @@
+#![expect(missing_docs)]
+
+use core::future::poll_fn;
+use core::task::Poll;
+
+use veecle_freertos_integration::task::{LocalCell, block_on_future};
+
+pub mod common;
+
+/// Returns `Pending` once, letting the other future run.
+async fn yield_now() {
+    let mut yielded = false;
+    poll_fn(|cx| {
+        if yielded {
+            return Poll::Ready(());
+        }
+        yielded = true;
+        cx.waker().wake_by_ref();
+        Poll::Pending
+    })
+    .await
+}
+
+#[common::apply(common::test)]
+fn task_local_cell() {
+    common::run_freertos_test(|| {
+        let counter = LocalCell::new(0);
+
+        let shared = &counter;
+        let increment = move || async move {
+            for _ in 0..3 {
+                *shared.borrow_mut() += 1;
+                yield_now().await;
+            }
+        };
+        block_on_future(futures::future::join(increment(), increment()));
+        assert_eq!(*counter.borrow_mut(), 6);
+
+        let held = async {
+            let mut guard = counter.borrow_mut();
+            yield_now().await;
+            *guard += 1;
+        };
+        let contended = async { counter.try_borrow_mut().is_none() };
+        let ((), was_borrowed) = block_on_future(futures::future::join(held, contended));
+
+        assert!(was_borrowed);
+        assert_eq!(counter.into_inner(), 7);
+    });
+}
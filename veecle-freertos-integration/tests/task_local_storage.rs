@@ -0,0 +1,36 @@
+#![expect(missing_docs)]
+
+use veecle_freertos_integration::task::TaskLocal;
+use veecle_freertos_integration::{CurrentTask, Duration, Task, TaskPriority};
+
+pub mod common;
+
+// SAFETY: Index 1 isn't used by anything else in this test.
+static LOCAL: TaskLocal<u32> = unsafe { TaskLocal::new(1) };
+
+#[common::apply(common::test)]
+fn task_local_storage() {
+    Task::new()
+        .priority(TaskPriority(2))
+        .start(|_| {
+            assert_eq!(*LOCAL.get_or_init(|| 7), 7);
+
+            CurrentTask::delay(Duration::infinite());
+        })
+        .unwrap();
+
+    common::run_freertos_test(|| {
+        let task = Task::current().unwrap();
+
+        let mut value = 5_u32;
+        // SAFETY: Index 0 isn't used by a `TaskLocal`.
+        unsafe { task.set_local_storage(0, (&raw mut value).cast()) };
+        assert_eq!(task.get_local_storage(0), (&raw mut value).cast());
+
+        // Each task has its own value.
+        assert_eq!(LOCAL.get(), None);
+        assert_eq!(*LOCAL.get_or_init(|| 42), 42);
+        assert_eq!(*LOCAL.get_or_init(|| 0), 42);
+        assert_eq!(LOCAL.get(), Some(&42));
+    });
+}
@@ -0,0 +1,18 @@
+#![expect(missing_docs)]
+
+use veecle_freertos_integration::{CurrentTask, Task, task};
+
+pub mod common;
+
+#[common::apply(common::test)]
+fn task_max_tasks_hint() {
+    common::run_freertos_test(|| {
+        for _ in 0..3 {
+            Task::new().start(|_| CurrentTask::suspend()).unwrap();
+        }
+
+        // The idle task, the timer daemon task, this task and the three tasks above.
+        assert!(task::max_tasks_hint() >= 6);
+        assert!(task::max_tasks_hint() > task::count() as usize);
+    });
+}
@@ -0,0 +1,36 @@
+#![expect(missing_docs)]
+
+use veecle_freertos_integration::{CurrentTask, Duration, TaskNotification};
+
+use crate::common::start_task;
+
+pub mod common;
+
+#[common::apply(common::test)]
+fn task_notify_and_query() {
+    const BITS_A: u32 = 0b01;
+    const BITS_B: u32 = 0b10;
+
+    let task = start_task(|_| {
+        let notification_value = CurrentTask::take_notification(true, Duration::zero());
+        assert_eq!(notification_value, BITS_A | BITS_B);
+
+        common::end_scheduler();
+    });
+
+    assert_eq!(
+        task.notify_and_query(TaskNotification::SetBits(BITS_A)),
+        Ok(0)
+    );
+    assert_eq!(
+        task.notify_and_query(TaskNotification::SetBits(BITS_B)),
+        Ok(BITS_A)
+    );
+    // The notification is still pending, so the value is not overwritten.
+    assert!(
+        task.notify_and_query(TaskNotification::SetValue(0))
+            .is_err()
+    );
+
+    veecle_freertos_integration::scheduler::start_scheduler();
+}
@@ -0,0 +1,36 @@
+#![expect(missing_docs)]
+
+use veecle_freertos_integration::{CurrentTask, Duration, InterruptContext, TaskNotification};
+
+use crate::common::start_task;
+
+pub mod common;
+
+#[common::apply(common::test)]
+fn task_notify_and_query_from_isr() {
+    let task = start_task(|task| {
+        let notification_value = task
+            .wait_for_notification(0, u32::MAX, Duration::from_ms(1000))
+            .unwrap();
+        assert_eq!(notification_value, 2);
+
+        common::end_scheduler();
+    });
+
+    start_task(move |_| {
+        let mut interrupt_context = InterruptContext::new();
+        assert_eq!(
+            task.notify_and_query_from_isr(&mut interrupt_context, TaskNotification::Increment),
+            Ok(0)
+        );
+        assert_eq!(
+            task.notify_and_query_from_isr(&mut interrupt_context, TaskNotification::Increment),
+            Ok(1)
+        );
+        drop(interrupt_context);
+
+        CurrentTask::suspend();
+    });
+
+    veecle_freertos_integration::scheduler::start_scheduler();
+}
@@ -0,0 +1,33 @@
+#![expect(missing_docs)]
+
+use veecle_freertos_integration::task::NotifyGroup;
+use veecle_freertos_integration::{CurrentTask, Duration, Queue, TaskNotification, TaskPriority};
+
+pub mod common;
+
+#[common::apply(common::test)]
+fn task_notify_group() {
+    let received: Queue<u32> = Queue::new(3).unwrap();
+
+    let start_member = |id: u32| {
+        let received = received.clone();
+        common::start_task_with(TaskPriority(2), 1024, move |task| {
+            let value = task
+                .wait_for_notification(0, u32::MAX, Duration::infinite())
+                .unwrap();
+            received.send(id * value, Duration::zero()).unwrap();
+            CurrentTask::suspend();
+        })
+    };
+    let group = NotifyGroup::new([start_member(1), start_member(2), start_member(3)]);
+
+    common::run_freertos_test(move || {
+        assert_eq!(received.messages_waiting(), 0);
+
+        group.notify_all(TaskNotification::SetValue(10));
+
+        let mut values: Vec<_> = received.drain().collect();
+        values.sort();
+        assert_eq!(values, [10, 20, 30]);
+    });
+}
@@ -0,0 +1,22 @@
+#![expect(missing_docs)]
+
+use veecle_freertos_integration::task::oneshot;
+use veecle_freertos_integration::{CurrentTask, Duration, FreeRtosError};
+
+pub mod common;
+
+#[common::apply(common::test)]
+fn task_oneshot() {
+    common::run_freertos_test(|| {
+        let (sender, receiver) = oneshot().unwrap();
+
+        assert_eq!(receiver.recv(Duration::zero()), Err(FreeRtosError::Timeout));
+
+        common::start_task(move |_| {
+            sender.send(0xC0FFEE);
+            CurrentTask::suspend();
+        });
+
+        assert_eq!(receiver.recv(Duration::from_ms(1000)), Ok(0xC0FFEE));
+    });
+}
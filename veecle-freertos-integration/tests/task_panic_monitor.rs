@@ -0,0 +1,29 @@
+#![expect(missing_docs)]
+
+pub mod common;
+
+#[common::apply(common::test)]
+fn task_panic_monitor() {
+    #[cfg(panic = "unwind")]
+    {
+        use veecle_freertos_integration::task::PanicMonitor;
+        use veecle_freertos_integration::{Duration, Task, TaskPriority};
+
+        common::run_freertos_test(|| {
+            let monitor = PanicMonitor::install(4).unwrap();
+            assert!(monitor.receive(Duration::zero()).is_none());
+
+            Task::new()
+                .name(c"panicking")
+                .priority(TaskPriority(2))
+                .catch_panics()
+                .start(|_| panic!("boom"))
+                .unwrap();
+
+            let (task, message) = monitor.receive(Duration::from_ms(100)).unwrap();
+            assert_eq!(task.get_name().unwrap(), "panicking");
+            assert_eq!(message, "boom");
+            assert_eq!(monitor.pending(), 0);
+        });
+    }
+}
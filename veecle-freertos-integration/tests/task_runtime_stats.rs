@@ -0,0 +1,20 @@
+#![expect(missing_docs)]
+
+pub mod common;
+
+#[common::apply(common::test)]
+#[requires(all(
+    any(
+        configUSE_STATS_FORMATTING_FUNCTIONS = "1",
+        configUSE_STATS_FORMATTING_FUNCTIONS = "2"
+    ),
+    configUSE_TRACE_FACILITY,
+    configGENERATE_RUN_TIME_STATS
+))]
+fn task_runtime_stats() {
+    common::run_freertos_test(move || {
+        let stats = veecle_freertos_integration::task::runtime_stats();
+        assert!(!stats.is_empty());
+        assert!(stats.contains("IDLE"), "{stats}");
+    });
+}
@@ -0,0 +1,30 @@
+#![expect(missing_docs)]
+
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use veecle_freertos_integration::{CurrentTask, Duration, Task, TaskPriority};
+
+pub mod common;
+
+#[common::apply(common::test)]
+fn task_start_delayed() {
+    static STARTED: AtomicBool = AtomicBool::new(false);
+
+    common::run_freertos_test(|| {
+        // A higher priority task would run the closure right away if it wasn't delayed.
+        Task::new()
+            .name(c"delayed")
+            .priority(TaskPriority(2))
+            .start_delayed(Duration::from_ms(50), |_| {
+                STARTED.store(true, Ordering::Release);
+                CurrentTask::suspend();
+            })
+            .unwrap();
+
+        CurrentTask::delay(Duration::from_ms(40));
+        assert!(!STARTED.load(Ordering::Acquire));
+
+        CurrentTask::delay(Duration::from_ms(20));
+        assert!(STARTED.load(Ordering::Acquire));
+    });
+}
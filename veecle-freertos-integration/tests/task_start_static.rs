@@ -0,0 +1,33 @@
+#![expect(missing_docs)]
+
+pub mod common;
+
+#[common::apply(common::test)]
+fn task_start_static() {
+    #[cfg(configSUPPORT_STATIC_ALLOCATION)]
+    {
+        use std::sync::atomic::{AtomicBool, Ordering};
+
+        use veecle_freertos_integration::{CurrentTask, Task, TaskPriority};
+        use veecle_freertos_sys::bindings::{StackType_t, StaticTask_t};
+
+        static RAN: AtomicBool = AtomicBool::new(false);
+
+        let stack: &'static mut [StackType_t] = Box::leak(vec![0; 1024].into_boxed_slice());
+        // SAFETY: `StaticTask_t` is a plain C struct, all zeroes is a valid value.
+        let task_buffer: &'static mut StaticTask_t =
+            Box::leak(Box::new(unsafe { core::mem::zeroed() }));
+
+        Task::new()
+            .priority(TaskPriority(2))
+            .start_static(stack, task_buffer, |_| {
+                RAN.store(true, Ordering::SeqCst);
+                CurrentTask::suspend();
+            })
+            .unwrap();
+
+        common::run_freertos_test(|| {
+            assert!(RAN.load(Ordering::SeqCst));
+        });
+    }
+}
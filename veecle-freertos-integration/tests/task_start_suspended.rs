@@ -0,0 +1,34 @@
+#![expect(missing_docs)]
+
+use std::sync::atomic::{AtomicU32, Ordering};
+
+use veecle_freertos_integration::{CurrentTask, Duration, Task, TaskPriority};
+
+pub mod common;
+
+#[common::apply(common::test)]
+fn task_start_suspended() {
+    static STARTED: AtomicU32 = AtomicU32::new(0);
+
+    common::run_freertos_test(|| {
+        // Higher priority tasks would preempt the test task right away if they weren't suspended.
+        let tasks = [c"first", c"second"].map(|name| {
+            Task::new()
+                .name(name)
+                .priority(TaskPriority(2))
+                .start_suspended(|_| {
+                    STARTED.fetch_add(1, Ordering::Release);
+                    CurrentTask::suspend();
+                })
+                .unwrap()
+        });
+
+        CurrentTask::delay(Duration::from_ms(10));
+        assert_eq!(STARTED.load(Ordering::Acquire), 0);
+
+        for task in &tasks {
+            task.resume();
+        }
+        assert_eq!(STARTED.load(Ordering::Acquire), 2);
+    });
+}
@@ -0,0 +1,30 @@
+#![expect(missing_docs)]
+
+use veecle_freertos_integration::task::{TaskState, system_state};
+use veecle_freertos_integration::{Task, TaskPriority};
+
+pub mod common;
+
+#[common::apply(common::test)]
+fn task_system_state() {
+    Task::new()
+        .name(c"idle_worker")
+        .priority(TaskPriority(1))
+        .start(|_| veecle_freertos_integration::CurrentTask::suspend())
+        .unwrap();
+
+    common::run_freertos_test(move || {
+        let current = Task::current().unwrap();
+        let statuses = system_state();
+
+        let status = statuses
+            .iter()
+            .find(|status| status.task.raw_handle() == current.raw_handle())
+            .expect("current task to be reported");
+        assert_eq!(status.name, current.get_name().unwrap());
+        assert_eq!(status.state, TaskState::Running);
+        assert!(status.stack_high_water_mark > 0);
+
+        assert!(statuses.iter().any(|status| status.name == "idle_worker"));
+    });
+}
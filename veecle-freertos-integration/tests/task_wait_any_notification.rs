@@ -0,0 +1,43 @@
+#![expect(missing_docs)]
+
+use veecle_freertos_integration::{
+    CurrentTask, Duration, Queue, Task, TaskNotification, TaskPriority,
+};
+
+pub mod common;
+
+#[common::apply(common::test)]
+fn task_wait_any_notification() {
+    let observed: Queue<(u32, u32)> = Queue::new(2).unwrap();
+
+    let waiter = Task::new()
+        .priority(TaskPriority(2))
+        .start({
+            let observed = observed.clone();
+            move |_| {
+                for _ in 0..2 {
+                    let (index, value) =
+                        CurrentTask::wait_any_notification(&[1, 2], Duration::infinite()).unwrap();
+                    observed
+                        .send((index as u32, value), Duration::zero())
+                        .unwrap();
+                }
+
+                CurrentTask::suspend();
+            }
+        })
+        .unwrap();
+
+    common::run_freertos_test(move || {
+        assert_eq!(
+            CurrentTask::wait_any_notification(&[1, 2], Duration::from_ms(10)),
+            None
+        );
+
+        waiter.notify_indexed(2, TaskNotification::SetValue(20));
+        assert_eq!(observed.receive(Duration::from_ms(1000)), Ok((2, 20)));
+
+        waiter.notify_indexed(1, TaskNotification::SetValue(10));
+        assert_eq!(observed.receive(Duration::from_ms(1000)), Ok((1, 10)));
+    });
+}
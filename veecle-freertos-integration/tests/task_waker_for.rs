@@ -0,0 +1,20 @@
+#![expect(missing_docs)]
+
+use veecle_freertos_integration::{CurrentTask, Duration, task};
+
+pub mod common;
+
+#[common::apply(common::test)]
+fn task_waker_for() {
+    common::run_freertos_test(|| {
+        let waker = task::waker_for(&CurrentTask::handle());
+
+        waker.wake_by_ref();
+        let cloned = waker.clone();
+        cloned.wake();
+        assert_eq!(CurrentTask::take_notification(true, Duration::zero()), 2);
+
+        waker.wake();
+        assert_eq!(CurrentTask::take_notification(true, Duration::zero()), 1);
+    });
+}
@@ -0,0 +1,21 @@
+#![expect(missing_docs)]
+
+use veecle_freertos_integration::task::{Timeout, block_on_future, with_timeout};
+use veecle_freertos_integration::{Duration, scheduler};
+
+pub mod common;
+
+#[common::apply(common::test)]
+fn task_with_timeout() {
+    common::run_freertos_test(|| {
+        let timeout = Duration::from_ms(20);
+
+        let start = scheduler::get_tick_count();
+        let result = block_on_future(with_timeout(timeout, core::future::pending::<()>()));
+        assert_eq!(result, Err(Timeout));
+        assert!(Duration::elapsed_since(start).ticks() >= timeout.ticks());
+
+        let result = block_on_future(with_timeout(timeout, async { 42 }));
+        assert_eq!(result, Ok(42));
+    });
+}
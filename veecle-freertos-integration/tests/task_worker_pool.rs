@@ -0,0 +1,30 @@
+#![expect(missing_docs)]
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU32, Ordering};
+
+use veecle_freertos_integration::{CurrentTask, Duration, TaskPriority, WorkerPool};
+
+pub mod common;
+
+#[common::apply(common::test)]
+fn task_worker_pool() {
+    const JOBS: u32 = 20;
+
+    common::run_freertos_test(|| {
+        let pool = WorkerPool::new(3, 4, TaskPriority(2)).unwrap();
+        let counter = Arc::new(AtomicU32::new(0));
+
+        for _ in 0..JOBS {
+            let counter = Arc::clone(&counter);
+            pool.submit(move || {
+                counter.fetch_add(1, Ordering::Relaxed);
+            });
+        }
+
+        CurrentTask::delay(Duration::from_ms(10));
+
+        assert_eq!(counter.load(Ordering::Relaxed), JOBS);
+        assert_eq!(pool.pending(), 0);
+    });
+}
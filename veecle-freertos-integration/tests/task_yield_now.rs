@@ -0,0 +1,37 @@
+#![expect(missing_docs)]
+
+use std::sync::atomic::{AtomicU32, Ordering};
+
+use veecle_freertos_integration::{CurrentTask, Duration};
+
+pub mod common;
+
+#[common::apply(common::test)]
+fn task_yield_now() {
+    const ITERATIONS: u32 = 100;
+
+    static FIRST: AtomicU32 = AtomicU32::new(0);
+    static SECOND: AtomicU32 = AtomicU32::new(0);
+
+    // Without yielding, the first task would finish all iterations within its time slice.
+    common::start_task(|_| {
+        for _ in 0..ITERATIONS {
+            FIRST.fetch_add(1, Ordering::Relaxed);
+            CurrentTask::delay(Duration::zero());
+        }
+
+        CurrentTask::suspend();
+    });
+
+    common::start_task(|_| {
+        for _ in 0..ITERATIONS {
+            let count = SECOND.fetch_add(1, Ordering::Relaxed) + 1;
+            assert!(FIRST.load(Ordering::Relaxed).abs_diff(count) <= 1);
+            CurrentTask::yield_now();
+        }
+
+        common::end_scheduler();
+    });
+
+    veecle_freertos_integration::scheduler::start_scheduler();
+}
@@ -0,0 +1,31 @@
+#![expect(missing_docs)]
+
+use veecle_freertos_integration::{Duration, FreeRtosError, Timer};
+use veecle_freertos_sys::bindings::{
+    configTIMER_QUEUE_LENGTH, vTaskResume, vTaskSuspend, xTimerGetTimerDaemonTaskHandle,
+};
+
+pub mod common;
+
+#[common::apply(common::test)]
+fn timers_command_queue_full() {
+    common::run_freertos_test(|| {
+        let timer = Timer::once(Some(c"timer_queue_full"), Duration::from_ms(10), |_| {}).unwrap();
+
+        // SAFETY: The scheduler is running, so the timer service task exists.
+        let daemon = unsafe { xTimerGetTimerDaemonTaskHandle() };
+        // Suspending the timer service task keeps it from processing the commands.
+        // SAFETY: The handle is a valid task handle.
+        unsafe { vTaskSuspend(daemon) };
+
+        for _ in 0..configTIMER_QUEUE_LENGTH {
+            assert_eq!(timer.handle().stop(), Ok(()));
+        }
+        let result = timer.handle().stop();
+
+        // SAFETY: The handle is a valid task handle.
+        unsafe { vTaskResume(daemon) };
+
+        assert_eq!(result, Err(FreeRtosError::TimerCommandQueueFull));
+    });
+}
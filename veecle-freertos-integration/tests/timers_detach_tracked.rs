@@ -0,0 +1,41 @@
+#![expect(missing_docs)]
+
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use veecle_freertos_integration::{CurrentTask, Duration, Timer, delete_tracked_timer};
+
+pub mod common;
+
+static CALLBACK_DROPPED: AtomicBool = AtomicBool::new(false);
+
+/// Captured by the timer callback to observe when the callback box is freed.
+struct DropFlag;
+
+impl Drop for DropFlag {
+    fn drop(&mut self) {
+        CALLBACK_DROPPED.store(true, Ordering::Release);
+    }
+}
+
+#[common::apply(common::test)]
+fn timers_detach_tracked() {
+    common::run_freertos_test(|| {
+        let flag = DropFlag;
+        let handle = Timer::periodic(Some(c"timer_tracked"), Duration::from_ms(10), move |_| {
+            let _ = &flag;
+        })
+        .unwrap()
+        .detach_tracked();
+        handle.start().unwrap();
+
+        CurrentTask::delay(Duration::from_ms(30));
+        assert!(!CALLBACK_DROPPED.load(Ordering::Acquire));
+
+        assert!(delete_tracked_timer(handle));
+        // The callback is dropped by the timer service task.
+        CurrentTask::delay(Duration::from_ms(10));
+        assert!(CALLBACK_DROPPED.load(Ordering::Acquire));
+
+        assert!(!delete_tracked_timer(handle));
+    });
+}
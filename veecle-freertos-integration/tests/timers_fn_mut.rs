@@ -0,0 +1,25 @@
+#![expect(missing_docs)]
+
+use veecle_freertos_integration::{Duration, Queue, Timer};
+
+pub mod common;
+
+#[common::apply(common::test)]
+fn timers_fn_mut() {
+    common::run_freertos_test(|| {
+        let queue: Queue<usize> = Queue::new(1).unwrap();
+        let timer_queue = queue.clone();
+
+        let mut ticks = 0;
+        let timer = Timer::periodic(Some(c"timer_fn_mut"), Duration::from_ms(10), move |_| {
+            ticks += 1;
+            if ticks == 3 {
+                timer_queue.send(ticks, Duration::zero()).unwrap();
+            }
+        })
+        .unwrap();
+        timer.handle().start().unwrap();
+
+        assert_eq!(queue.receive(Duration::from_ms(1000)), Ok(3));
+    });
+}
@@ -0,0 +1,56 @@
+#![expect(missing_docs)]
+
+use std::sync::atomic::AtomicU32;
+use std::sync::atomic::Ordering::{Acquire, Release};
+
+use veecle_freertos_integration::{CurrentTask, Duration, FreeRtosError, InterruptContext, Timer};
+
+pub mod common;
+
+// TODO: call `change_period_from_isr` and `stop_from_isr` from an interrupt.
+// https://veecle.atlassian.net/browse/DEV-101
+#[common::apply(common::test)]
+fn timers_from_isr_reconfigure() {
+    common::run_freertos_test(|| {
+        static CALLBACK_COUNT: AtomicU32 = AtomicU32::new(0);
+
+        let timer = Timer::periodic(Some(c"timer"), Duration::from_ms(1000), |_| {
+            CALLBACK_COUNT.fetch_add(1, Release);
+        })
+        .unwrap();
+
+        let mut interrupt_context = InterruptContext::new();
+        assert_eq!(
+            timer
+                .handle()
+                .change_period_from_isr(Duration::zero(), &mut interrupt_context),
+            Err(FreeRtosError::ZeroDuration)
+        );
+        assert!(!interrupt_context.should_yield());
+
+        // Changing the period also starts the stopped timer.
+        timer
+            .handle()
+            .change_period_from_isr(Duration::from_ms(20), &mut interrupt_context)
+            .unwrap();
+        assert!(interrupt_context.should_yield());
+        drop(interrupt_context);
+
+        CurrentTask::delay(Duration::from_ms(110));
+        assert!(CALLBACK_COUNT.load(Acquire) >= 3);
+
+        let mut interrupt_context = InterruptContext::new();
+        timer
+            .handle()
+            .stop_from_isr(&mut interrupt_context)
+            .unwrap();
+        drop(interrupt_context);
+
+        // Let the timer service task process the stop command.
+        CurrentTask::delay(Duration::from_ms(10));
+        let count = CALLBACK_COUNT.load(Acquire);
+
+        CurrentTask::delay(Duration::from_ms(100));
+        assert_eq!(CALLBACK_COUNT.load(Acquire), count);
+    });
+}
@@ -0,0 +1,42 @@
+#![expect(missing_docs)]
+
+use std::sync::atomic::{AtomicU32, Ordering};
+
+use veecle_freertos_integration::{
+    CurrentTask, Duration, InterruptContext, pend_function_call, pend_function_call_from_isr,
+};
+
+pub mod common;
+
+#[common::apply(common::test)]
+fn timers_pend_function_call() {
+    static CALLED: AtomicU32 = AtomicU32::new(0);
+
+    common::run_freertos_test(|| {
+        let value = 7;
+        pend_function_call(
+            move || {
+                CALLED.fetch_add(value, Ordering::Release);
+            },
+            Duration::from_ms(10),
+        )
+        .unwrap();
+
+        CurrentTask::delay(Duration::from_ms(10));
+        assert_eq!(CALLED.load(Ordering::Acquire), 7);
+
+        InterruptContext::scope(|context| {
+            pend_function_call_from_isr(
+                context,
+                |parameter| {
+                    CALLED.fetch_add(parameter, Ordering::Release);
+                },
+                3,
+            )
+            .unwrap();
+        });
+
+        CurrentTask::delay(Duration::from_ms(10));
+        assert_eq!(CALLED.load(Ordering::Acquire), 10);
+    });
+}
@@ -0,0 +1,29 @@
+#![expect(missing_docs)]
+
+use std::sync::atomic::{AtomicU32, Ordering};
+
+use veecle_freertos_integration::{CurrentTask, Duration, Timer};
+
+pub mod common;
+
+#[common::apply(common::test)]
+fn timers_reload_mode() {
+    static EXPIRATIONS: AtomicU32 = AtomicU32::new(0);
+
+    common::run_freertos_test(|| {
+        let timer = Timer::once(Some(c"timer_reload"), Duration::from_ms(10), |_| {
+            EXPIRATIONS.fetch_add(1, Ordering::Relaxed);
+        })
+        .unwrap();
+        assert!(!timer.handle().reload_mode());
+
+        timer.handle().set_reload_mode(true);
+        assert!(timer.handle().reload_mode());
+
+        timer.handle().start().unwrap();
+        CurrentTask::delay(Duration::from_ms(55));
+        timer.handle().stop().unwrap();
+
+        assert!(EXPIRATIONS.load(Ordering::Relaxed) >= 4);
+    });
+}
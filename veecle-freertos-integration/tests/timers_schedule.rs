@@ -0,0 +1,43 @@
+#![expect(missing_docs)]
+
+use std::sync::atomic::{AtomicU32, Ordering};
+
+use veecle_freertos_integration::{CurrentTask, Duration, FreeRtosError, Schedule};
+
+pub mod common;
+
+#[common::apply(common::test)]
+fn timers_schedule() {
+    static FAST: AtomicU32 = AtomicU32::new(0);
+    static SLOW: AtomicU32 = AtomicU32::new(0);
+    static REARM_FAILURES: AtomicU32 = AtomicU32::new(0);
+
+    common::run_freertos_test(|| {
+        assert_eq!(
+            Schedule::new().start().unwrap_err(),
+            FreeRtosError::ZeroDuration
+        );
+
+        let schedule = Schedule::new()
+            .name(c"schedule")
+            .every(Duration::from_ms(10), || {
+                FAST.fetch_add(1, Ordering::Relaxed);
+            })
+            .every(Duration::from_ms(25), || {
+                SLOW.fetch_add(1, Ordering::Relaxed);
+            })
+            .on_rearm_failure(|_| {
+                REARM_FAILURES.fetch_add(1, Ordering::Relaxed);
+            })
+            .start()
+            .unwrap();
+
+        CurrentTask::delay(Duration::from_ms(105));
+        drop(schedule);
+
+        // Jobs are due at 10, 20, ..., 100 ms and 25, 50, 75, 100 ms, allow some jitter of the POSIX port.
+        assert!((9..=11).contains(&FAST.load(Ordering::Relaxed)));
+        assert!((3..=5).contains(&SLOW.load(Ordering::Relaxed)));
+        assert_eq!(REARM_FAILURES.load(Ordering::Relaxed), 0);
+    });
+}
@@ -0,0 +1,23 @@
+#![expect(missing_docs)]
+
+use veecle_freertos_integration::task::block_on_future;
+use veecle_freertos_integration::{Duration, scheduler, sleep};
+
+pub mod common;
+
+#[common::apply(common::test)]
+fn timers_sleep() {
+    common::run_freertos_test(|| {
+        let duration = Duration::from_ms(50);
+
+        let start = scheduler::get_tick_count();
+        block_on_future(sleep(duration));
+        let elapsed = Duration::elapsed_since(start);
+
+        assert!(elapsed.ticks() >= duration.ticks());
+        assert!(elapsed.ticks() < duration.ticks() + Duration::from_ms(50).ticks());
+
+        // An already elapsed sleep completes without creating a timer.
+        block_on_future(sleep(Duration::zero()));
+    });
+}
@@ -0,0 +1,36 @@
+#![expect(missing_docs)]
+
+use futures::FutureExt;
+use veecle_freertos_integration::task::block_on_future;
+use veecle_freertos_integration::{Duration, Timer, sleep};
+use veecle_freertos_sys::bindings::{
+    configTIMER_QUEUE_LENGTH, vTaskResume, vTaskSuspend, xTimerGetTimerDaemonTaskHandle,
+};
+
+pub mod common;
+
+#[common::apply(common::test)]
+fn timers_sleep_command_queue_full() {
+    common::run_freertos_test(|| {
+        let timer = Timer::once(Some(c"timer_queue_full"), Duration::from_ms(10), |_| {}).unwrap();
+
+        // SAFETY: The scheduler is running, so the timer service task exists.
+        let daemon = unsafe { xTimerGetTimerDaemonTaskHandle() };
+        // Suspending the timer service task keeps it from processing the commands.
+        // SAFETY: The handle is a valid task handle.
+        unsafe { vTaskSuspend(daemon) };
+
+        for _ in 0..configTIMER_QUEUE_LENGTH {
+            assert_eq!(timer.handle().stop(), Ok(()));
+        }
+
+        // Starting the sleep timer fails, which is retried instead of panicking.
+        let mut sleep = sleep(Duration::from_ms(200));
+        assert_eq!((&mut sleep).now_or_never(), None);
+
+        // SAFETY: The handle is a valid task handle.
+        unsafe { vTaskResume(daemon) };
+
+        block_on_future(sleep);
+    });
+}
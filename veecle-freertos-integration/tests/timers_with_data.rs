@@ -0,0 +1,36 @@
+#![expect(missing_docs)]
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU32, Ordering};
+
+use veecle_freertos_integration::{CurrentTask, Duration, Timer};
+
+pub mod common;
+
+#[derive(Default)]
+struct Counter {
+    expirations: AtomicU32,
+}
+
+#[common::apply(common::test)]
+fn timers_with_data() {
+    common::run_freertos_test(|| {
+        let counter = Arc::new(Counter::default());
+
+        let timer = Timer::periodic_with_data(
+            Some(c"timer_with_data"),
+            Duration::from_ms(10),
+            Arc::clone(&counter),
+            |_, counter: &Arc<Counter>| {
+                counter.expirations.fetch_add(1, Ordering::Relaxed);
+            },
+        )
+        .unwrap();
+        timer.handle().start().unwrap();
+
+        CurrentTask::delay(Duration::from_ms(55));
+        timer.handle().stop().unwrap();
+
+        assert!(counter.expirations.load(Ordering::Relaxed) >= 4);
+    });
+}
@@ -25,4 +25,11 @@ fn units() {
     assert_eq!(Duration::from_ticks(0), Duration::zero());
     assert_eq!(Duration::from_ticks(100), Duration::from_ticks(100));
     assert_eq!(Duration::from_ticks(100).ticks(), 100);
+
+    assert_eq!(Duration::from_ticks(100), 100);
+    assert_ne!(Duration::from_ticks(100), 101);
+    assert!(Duration::from_ticks(100) > 99);
+    assert!(Duration::from_ticks(100) < 101);
+    assert!(Duration::from_ticks(100) >= 100);
+    assert!(Duration::zero() <= 0);
 }
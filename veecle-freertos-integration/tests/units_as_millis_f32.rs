@@ -0,0 +1,24 @@
+#![expect(missing_docs)]
+
+pub mod common;
+
+use veecle_freertos_integration::Duration;
+use veecle_freertos_sys::bindings::configTICK_RATE_HZ;
+
+#[common::apply(common::test)]
+fn units_as_millis_f32() {
+    let tick_ms = 1000.0 / f32::from(configTICK_RATE_HZ);
+
+    assert_eq!(Duration::zero().as_millis_f32(), 0.0);
+    assert_eq!(Duration::eps().as_millis_f32(), tick_ms);
+
+    for ticks in [1, 3, 7, 250, 1001] {
+        let duration = Duration::from_ticks(ticks);
+        let millis = duration.as_millis_f32();
+
+        assert!((millis - ticks as f32 * tick_ms).abs() < 0.001);
+        // The integer conversion truncates, the float conversion keeps the fraction.
+        assert!(millis >= duration.ms() as f32);
+        assert!(millis < duration.ms() as f32 + 1.0);
+    }
+}
@@ -0,0 +1,28 @@
+#![expect(missing_docs)]
+
+use veecle_freertos_integration::Duration;
+use veecle_freertos_integration::scheduler::get_tick_count;
+use veecle_freertos_sys::bindings::{TickType_t, vTaskDelay};
+
+pub mod common;
+
+#[common::apply(common::test)]
+fn units_elapsed_since() {
+    assert_eq!(Duration::between(10, 25), Duration::from_ticks(15));
+    assert_eq!(Duration::between(25, 25), Duration::zero());
+    assert_eq!(
+        Duration::between(TickType_t::MAX, 0),
+        Duration::from_ticks(1)
+    );
+    assert_eq!(
+        Duration::between(TickType_t::MAX - 2, 4),
+        Duration::from_ticks(7)
+    );
+
+    common::run_freertos_test(|| {
+        let start = get_tick_count();
+        vTaskDelay(5);
+
+        assert!(Duration::elapsed_since(start) >= 5);
+    });
+}
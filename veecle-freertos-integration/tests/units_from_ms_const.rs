@@ -0,0 +1,24 @@
+#![expect(missing_docs)]
+
+use veecle_freertos_integration::Duration;
+use veecle_freertos_sys::bindings::{TickType_t, configTICK_RATE_HZ};
+
+pub mod common;
+
+const TIMEOUT: Duration = Duration::from_ms_const(250);
+const SHORT: Duration = Duration::from_ms_const(1);
+const NONE: Duration = Duration::from_ms_const(0);
+
+#[common::apply(common::test)]
+fn units_from_ms_const() {
+    assert_eq!(
+        TIMEOUT.ticks(),
+        250 * TickType_t::from(configTICK_RATE_HZ) / 1000
+    );
+    assert!(SHORT.ticks() >= 1);
+    assert_eq!(NONE, Duration::zero());
+
+    for ms in [0, 1, 2, 9, 10, 99, 100, 1000, 1234, 60_000] {
+        assert_eq!(Duration::from_ms_const(ms), Duration::from_ms(ms));
+    }
+}
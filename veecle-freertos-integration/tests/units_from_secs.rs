@@ -0,0 +1,25 @@
+#![expect(missing_docs)]
+
+use veecle_freertos_integration::{Duration, TickType_t, tick_rate_hz};
+
+pub mod common;
+
+#[common::apply(common::test)]
+fn units_from_secs() {
+    let hz = TickType_t::from(tick_rate_hz());
+
+    assert_eq!(Duration::from_secs(5).ticks(), 5 * hz);
+    assert_eq!(Duration::from_mins(2).ticks(), 120 * hz);
+    assert_eq!(Duration::from_secs(0), Duration::zero());
+
+    // Saturates with a 32-bit `TickType_t`, converts exactly with a 64-bit one.
+    let large = Duration::from_mins(u32::MAX);
+    match TickType_t::from(u32::MAX)
+        .checked_mul(60)
+        .and_then(|seconds| seconds.checked_mul(hz))
+    {
+        Some(ticks) => assert_eq!(large.ticks(), ticks),
+        None => assert_eq!(large.ticks(), Duration::infinite().ticks() - 1),
+    }
+    assert!(large < Duration::infinite());
+}
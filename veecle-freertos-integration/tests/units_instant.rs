@@ -0,0 +1,26 @@
+#![expect(missing_docs)]
+
+use veecle_freertos_integration::{CurrentTask, Duration, Instant, TickType_t};
+
+pub mod common;
+
+#[common::apply(common::test)]
+fn units_instant() {
+    let before_wrap = Instant::from_ticks(TickType_t::MAX - 1);
+    let after_wrap = Instant::from_ticks(3);
+    assert_eq!(after_wrap.duration_since(before_wrap).ticks(), 5);
+
+    common::run_freertos_test(|| {
+        let start = Instant::now();
+
+        CurrentTask::delay(Duration::from_ms(10));
+
+        assert!(start.elapsed() >= Duration::from_ms(10));
+
+        let later = Instant::now();
+        assert_eq!(
+            later.duration_since(start).ticks(),
+            later.ticks() - start.ticks()
+        );
+    });
+}
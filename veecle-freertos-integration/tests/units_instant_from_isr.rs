@@ -0,0 +1,23 @@
+#![expect(missing_docs)]
+
+use veecle_freertos_integration::{CurrentTask, Duration, InstantFromIsr};
+
+pub mod common;
+
+#[common::apply(common::test)]
+fn units_instant_from_isr() {
+    common::run_freertos_test(|| {
+        let start = InstantFromIsr::now();
+
+        CurrentTask::delay(Duration::from_ms(10));
+
+        assert!(start.elapsed() >= Duration::from_ms(10));
+
+        let later = InstantFromIsr::now();
+        assert!(later > start);
+        assert_eq!(
+            later.duration_since(start).ticks(),
+            later.ticks() - start.ticks()
+        );
+    });
+}
@@ -0,0 +1,31 @@
+#![expect(missing_docs)]
+
+pub mod common;
+
+use veecle_freertos_integration::{Duration, TaskPriority, UBaseType_t};
+use veecle_freertos_sys::bindings::{
+    TickType_t, configMAX_PRIORITIES, configTICK_RATE_HZ, pdMS_TO_TICKS, pdTICKS_TO_MS,
+    tskIDLE_PRIORITY,
+};
+
+#[common::apply(common::test)]
+fn units_ms_to_ticks() {
+    for ms in [0, 1, 2, 9, 10, 99, 100, 1000, 1234, 60_000] {
+        let ticks = if ms == 0 { 0 } else { pdMS_TO_TICKS(ms).max(1) };
+        assert_eq!(Duration::from_ms(ms).ticks(), ticks);
+    }
+
+    // Half a tick period, rounded up to a whole millisecond.
+    let sub_tick_ms = (1000 / TickType_t::from(configTICK_RATE_HZ) / 2).max(1);
+    assert_eq!(Duration::from_ms(sub_tick_ms), Duration::eps());
+
+    for ticks in [0, 1, 2, 10, 100, 1000, 60_000] {
+        assert_eq!(Duration::from_ticks(ticks).ms(), pdTICKS_TO_MS(ticks));
+    }
+
+    assert_eq!(TaskPriority::idle().0, tskIDLE_PRIORITY());
+    assert_eq!(
+        TaskPriority::max().0,
+        configMAX_PRIORITIES as UBaseType_t - 1
+    );
+}
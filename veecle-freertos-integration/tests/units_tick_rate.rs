@@ -0,0 +1,13 @@
+#![expect(missing_docs)]
+
+use veecle_freertos_integration::{Duration, tick_rate_hz};
+
+pub mod common;
+
+#[common::apply(common::test)]
+fn units_tick_rate() {
+    assert!(tick_rate_hz() > 0);
+
+    let one_second = Duration::from_ticks(tick_rate_hz().into());
+    assert_eq!(one_second.ms(), 1000);
+}
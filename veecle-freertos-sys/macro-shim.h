@@ -49,6 +49,10 @@ void shim_taskYIELD(){
     taskYIELD();
 }
 
+void shim_portYIELD_FROM_ISR(BaseType_t xHigherPriorityTaskWoken){
+    portYIELD_FROM_ISR(xHigherPriorityTaskWoken);
+}
+
 BaseType_t shim_xQueueSendToBackFromISR
 (
     QueueHandle_t xQueue,
@@ -76,6 +80,18 @@ QueueHandle_t shim_xQueueCreate
     return xQueueCreate(uxQueueLength, uxItemSize);
 }
 
+#if ( configSUPPORT_STATIC_ALLOCATION == 1 )
+QueueHandle_t shim_xQueueCreateStatic
+(
+    UBaseType_t uxQueueLength,
+    UBaseType_t uxItemSize,
+    uint8_t *pucQueueStorage,
+    StaticQueue_t *pxQueueBuffer
+){
+    return xQueueCreateStatic(uxQueueLength, uxItemSize, pucQueueStorage, pxQueueBuffer);
+}
+#endif
+
 BaseType_t shim_xQueueReceive
 (
     QueueHandle_t xQueue,
@@ -145,6 +161,14 @@ BaseType_t shim_xTimerStop
     return xTimerStop(xTimer, xBlockTime);
 }
 
+BaseType_t shim_xTimerStopFromISR
+(
+    TimerHandle_t xTimer,
+    BaseType_t *pxHigherPriorityTaskWoken
+){
+    return xTimerStopFromISR(xTimer, pxHigherPriorityTaskWoken);
+}
+
 BaseType_t shim_xTimerChangePeriod
 (
     TimerHandle_t xTimer,
@@ -154,6 +178,15 @@ BaseType_t shim_xTimerChangePeriod
     return xTimerChangePeriod(xTimer, xNewPeriod, xBlockTime);
 }
 
+BaseType_t shim_xTimerChangePeriodFromISR
+(
+    TimerHandle_t xTimer,
+    TickType_t xNewPeriod,
+    BaseType_t *pxHigherPriorityTaskWoken
+){
+    return xTimerChangePeriodFromISR(xTimer, xNewPeriod, pxHigherPriorityTaskWoken);
+}
+
 BaseType_t shim_xTimerDelete
 (
     TimerHandle_t xTimer,
@@ -161,3 +194,41 @@ BaseType_t shim_xTimerDelete
 ){
     return xTimerDelete(xTimer, xBlockTime);
 }
+
+void shim_taskENTER_CRITICAL(){
+    taskENTER_CRITICAL();
+}
+
+void shim_taskEXIT_CRITICAL(){
+    taskEXIT_CRITICAL();
+}
+
+TickType_t shim_pdMS_TO_TICKS(TickType_t xTimeInMs){
+    return pdMS_TO_TICKS(xTimeInMs);
+}
+
+TickType_t shim_pdTICKS_TO_MS(TickType_t xTimeInTicks){
+    return pdTICKS_TO_MS(xTimeInTicks);
+}
+
+UBaseType_t shim_tskIDLE_PRIORITY(){
+    return tskIDLE_PRIORITY;
+}
+
+#if ( configGENERATE_RUN_TIME_STATS == 1 )
+uint32_t shim_portGET_RUN_TIME_COUNTER_VALUE(){
+    return ( uint32_t ) portGET_RUN_TIME_COUNTER_VALUE();
+}
+#endif
+
+BaseType_t shim_taskSCHEDULER_SUSPENDED(){
+    return taskSCHEDULER_SUSPENDED;
+}
+
+BaseType_t shim_taskSCHEDULER_NOT_STARTED(){
+    return taskSCHEDULER_NOT_STARTED;
+}
+
+BaseType_t shim_taskSCHEDULER_RUNNING(){
+    return taskSCHEDULER_RUNNING;
+}
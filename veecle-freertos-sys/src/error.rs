@@ -5,6 +5,7 @@ use core::fmt::Display;
 /// Basic error type for the library.
 #[expect(missing_docs)]
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
 pub enum FreeRtosError {
     OutOfMemory,
     QueueSendTimeout,
@@ -17,6 +18,11 @@ pub enum FreeRtosError {
     InvalidQueueSize,
     ProcessorHasShutDown,
     ZeroDuration,
+    QueueClosed,
+    QueueSetAddFailed,
+    TimerCommandQueueFull,
+    FrameTooLong,
+    RunTimeCounterFrequencyUnknown,
 }
 
 impl core::error::Error for FreeRtosError {}
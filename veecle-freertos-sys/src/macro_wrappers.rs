@@ -3,8 +3,10 @@
 #![allow(non_snake_case)]
 
 use crate::bindings::{
-    BaseType_t, TickType_t, shim_pdFALSE, shim_pdTRUE, shim_portMAX_DELAY, shim_portTICK_PERIOD_MS,
-    shim_taskYIELD,
+    BaseType_t, TickType_t, UBaseType_t, shim_pdFALSE, shim_pdMS_TO_TICKS, shim_pdTICKS_TO_MS,
+    shim_pdTRUE, shim_portMAX_DELAY, shim_portTICK_PERIOD_MS, shim_portYIELD_FROM_ISR,
+    shim_taskSCHEDULER_NOT_STARTED, shim_taskSCHEDULER_RUNNING, shim_taskSCHEDULER_SUSPENDED,
+    shim_taskYIELD, shim_tskIDLE_PRIORITY,
 };
 
 /// Wraps `portTICK_PERIOD_MS` macro in a function.
@@ -35,3 +37,47 @@ pub fn taskYIELD() {
     // SAFETY: No requirements on the caller.
     unsafe { shim_taskYIELD() }
 }
+
+/// Wraps `portYIELD_FROM_ISR` macro in a function.
+///
+/// Requests a context switch on interrupt exit if `xHigherPriorityTaskWoken` is not `pdFALSE`.
+pub fn portYIELD_FROM_ISR(xHigherPriorityTaskWoken: BaseType_t) {
+    // SAFETY: No requirements on the caller.
+    unsafe { shim_portYIELD_FROM_ISR(xHigherPriorityTaskWoken) }
+}
+
+/// Wraps `pdMS_TO_TICKS` macro in a function.
+pub fn pdMS_TO_TICKS(xTimeInMs: TickType_t) -> TickType_t {
+    // SAFETY: No requirements on the caller.
+    unsafe { shim_pdMS_TO_TICKS(xTimeInMs) }
+}
+
+/// Wraps `pdTICKS_TO_MS` macro in a function.
+pub fn pdTICKS_TO_MS(xTimeInTicks: TickType_t) -> TickType_t {
+    // SAFETY: No requirements on the caller.
+    unsafe { shim_pdTICKS_TO_MS(xTimeInTicks) }
+}
+
+/// Wraps `tskIDLE_PRIORITY` macro in a function.
+pub fn tskIDLE_PRIORITY() -> UBaseType_t {
+    // SAFETY: No requirements on the caller.
+    unsafe { shim_tskIDLE_PRIORITY() }
+}
+
+/// Wraps `taskSCHEDULER_SUSPENDED` macro in a function.
+pub fn taskSCHEDULER_SUSPENDED() -> BaseType_t {
+    // SAFETY: No requirements on the caller.
+    unsafe { shim_taskSCHEDULER_SUSPENDED() }
+}
+
+/// Wraps `taskSCHEDULER_NOT_STARTED` macro in a function.
+pub fn taskSCHEDULER_NOT_STARTED() -> BaseType_t {
+    // SAFETY: No requirements on the caller.
+    unsafe { shim_taskSCHEDULER_NOT_STARTED() }
+}
+
+/// Wraps `taskSCHEDULER_RUNNING` macro in a function.
+pub fn taskSCHEDULER_RUNNING() -> BaseType_t {
+    // SAFETY: No requirements on the caller.
+    unsafe { shim_taskSCHEDULER_RUNNING() }
+}
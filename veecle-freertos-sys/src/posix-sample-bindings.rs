@@ -119,7 +119,8 @@ pub const configUSE_APPLICATION_TASK_TAG: u8 = 1;
 pub const configUSE_COUNTING_SEMAPHORES: u8 = 1;
 pub const configUSE_QUEUE_SETS: u8 = 1;
 pub const configUSE_TASK_NOTIFICATIONS: u8 = 1;
-pub const configTASK_NOTIFICATION_ARRAY_ENTRIES: u8 = 1;
+pub const configTASK_NOTIFICATION_ARRAY_ENTRIES: u8 = 3;
+pub const configNUM_THREAD_LOCAL_STORAGE_POINTERS: u8 = 4;
 pub const configUSE_TIMERS: u8 = 1;
 pub const configTIMER_QUEUE_LENGTH: u8 = 20;
 pub const configMAX_PRIORITIES: u8 = 7;
@@ -293,7 +294,6 @@ pub const INCLUDE_xTaskGetHandle: u8 = 0;
 pub const INCLUDE_uxTaskGetStackHighWaterMark2: u8 = 0;
 pub const INCLUDE_xTaskResumeFromISR: u8 = 1;
 pub const INCLUDE_xTaskGetCurrentTaskHandle: u8 = 1;
-pub const configNUM_THREAD_LOCAL_STORAGE_POINTERS: u8 = 0;
 pub const configUSE_EVENT_GROUPS: u8 = 1;
 pub const configUSE_STREAM_BUFFERS: u8 = 1;
 pub const configUSE_DAEMON_TASK_STARTUP_HOOK: u8 = 0;
@@ -317,8 +317,8 @@ pub const configAPPLICATION_ALLOCATED_HEAP: u8 = 0;
 pub const configENABLE_HEAP_PROTECTOR: u8 = 0;
 pub const configUSE_POSIX_ERRNO: u8 = 0;
 pub const configUSE_SB_COMPLETED_CALLBACK: u8 = 0;
-pub const configSUPPORT_STATIC_ALLOCATION: u8 = 0;
-pub const configKERNEL_PROVIDED_STATIC_MEMORY: u8 = 0;
+pub const configSUPPORT_STATIC_ALLOCATION: u8 = 1;
+pub const configKERNEL_PROVIDED_STATIC_MEMORY: u8 = 1;
 pub const configSUPPORT_DYNAMIC_ALLOCATION: u8 = 1;
 pub const configSTATS_BUFFER_MAX_LENGTH: u16 = 65535;
 pub const configINITIAL_TICK_COUNT: u8 = 0;
@@ -469,6 +469,9 @@ unsafe extern "C" {
         ulLine: ::core::ffi::c_ulong,
     );
 }
+unsafe extern "C" {
+    pub fn vQueueEventCalled(xQueue: *mut ::core::ffi::c_void, ulEvent: ::core::ffi::c_ulong);
+}
 pub type TaskFunction_t = ::core::option::Option<
     unsafe extern "C" fn(arg: *mut ::core::ffi::c_void),
 >;
@@ -2280,6 +2283,17 @@ unsafe extern "C" {
         pxCreatedTask: *mut TaskHandle_t,
     ) -> BaseType_t;
 }
+unsafe extern "C" {
+    pub fn xTaskCreateStatic(
+        pxTaskCode: TaskFunction_t,
+        pcName: *const ::core::ffi::c_char,
+        uxStackDepth: StackType_t,
+        pvParameters: *mut ::core::ffi::c_void,
+        uxPriority: UBaseType_t,
+        puxStackBuffer: *mut StackType_t,
+        pxTaskBuffer: *mut StaticTask_t,
+    ) -> TaskHandle_t;
+}
 unsafe extern "C" {
     /**
 ```text
@@ -3091,6 +3105,19 @@ unsafe extern "C" {
 ```*/
     pub fn xTaskGetApplicationTaskTagFromISR(xTask: TaskHandle_t) -> TaskHookFunction_t;
 }
+unsafe extern "C" {
+    pub fn vTaskSetThreadLocalStoragePointer(
+        xTaskToSet: TaskHandle_t,
+        xIndex: BaseType_t,
+        pvValue: *mut ::core::ffi::c_void,
+    );
+}
+unsafe extern "C" {
+    pub fn pvTaskGetThreadLocalStoragePointer(
+        xTaskToQuery: TaskHandle_t,
+        xIndex: BaseType_t,
+    ) -> *mut ::core::ffi::c_void;
+}
 unsafe extern "C" {
     pub fn xTaskCallApplicationTaskHook(
         xTask: TaskHandle_t,
@@ -6445,6 +6472,9 @@ unsafe extern "C" {
 unsafe extern "C" {
     pub fn shim_taskYIELD();
 }
+unsafe extern "C" {
+    pub fn shim_portYIELD_FROM_ISR(xHigherPriorityTaskWoken: BaseType_t);
+}
 unsafe extern "C" {
     pub fn shim_xQueueSendToBackFromISR(
         xQueue: QueueHandle_t,
@@ -6465,6 +6495,14 @@ unsafe extern "C" {
         uxItemSize: UBaseType_t,
     ) -> QueueHandle_t;
 }
+unsafe extern "C" {
+    pub fn shim_xQueueCreateStatic(
+        uxQueueLength: UBaseType_t,
+        uxItemSize: UBaseType_t,
+        pucQueueStorage: *mut u8,
+        pxQueueBuffer: *mut StaticQueue_t,
+    ) -> QueueHandle_t;
+}
 unsafe extern "C" {
     pub fn shim_xQueueReceive(
         xQueue: QueueHandle_t,
@@ -6513,6 +6551,12 @@ unsafe extern "C" {
 unsafe extern "C" {
     pub fn shim_xTimerStop(xTimer: TimerHandle_t, xBlockTime: TickType_t) -> BaseType_t;
 }
+unsafe extern "C" {
+    pub fn shim_xTimerStopFromISR(
+        xTimer: TimerHandle_t,
+        pxHigherPriorityTaskWoken: *mut BaseType_t,
+    ) -> BaseType_t;
+}
 unsafe extern "C" {
     pub fn shim_xTimerChangePeriod(
         xTimer: TimerHandle_t,
@@ -6520,9 +6564,43 @@ unsafe extern "C" {
         xBlockTime: TickType_t,
     ) -> BaseType_t;
 }
+unsafe extern "C" {
+    pub fn shim_xTimerChangePeriodFromISR(
+        xTimer: TimerHandle_t,
+        xNewPeriod: TickType_t,
+        pxHigherPriorityTaskWoken: *mut BaseType_t,
+    ) -> BaseType_t;
+}
 unsafe extern "C" {
     pub fn shim_xTimerDelete(
         xTimer: TimerHandle_t,
         xBlockTime: TickType_t,
     ) -> BaseType_t;
 }
+unsafe extern "C" {
+    pub fn shim_taskENTER_CRITICAL();
+}
+unsafe extern "C" {
+    pub fn shim_taskEXIT_CRITICAL();
+}
+unsafe extern "C" {
+    pub fn shim_pdMS_TO_TICKS(xTimeInMs: TickType_t) -> TickType_t;
+}
+unsafe extern "C" {
+    pub fn shim_pdTICKS_TO_MS(xTimeInTicks: TickType_t) -> TickType_t;
+}
+unsafe extern "C" {
+    pub fn shim_tskIDLE_PRIORITY() -> UBaseType_t;
+}
+unsafe extern "C" {
+    pub fn shim_portGET_RUN_TIME_COUNTER_VALUE() -> u32;
+}
+unsafe extern "C" {
+    pub fn shim_taskSCHEDULER_SUSPENDED() -> BaseType_t;
+}
+unsafe extern "C" {
+    pub fn shim_taskSCHEDULER_NOT_STARTED() -> BaseType_t;
+}
+unsafe extern "C" {
+    pub fn shim_taskSCHEDULER_RUNNING() -> BaseType_t;
+}